@@ -0,0 +1,14 @@
+//! Checks that the search and `TicTacToe` build with
+//! `alloc` alone, as they would for an embedded target.
+#![no_std]
+
+use minimax_alpha_beta::games::TicTacToe;
+use minimax_alpha_beta::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+use minimax_alpha_beta::strategy::game_strategy::GameStrategy;
+
+/// The engine's reply to the maximizer opening in the center.
+pub fn reply_to_center() -> usize {
+    let mut ttt = TicTacToe::new(3);
+    ttt.play(&4, true);
+    ttt.get_best_move(9, true)
+}
@@ -0,0 +1,99 @@
+use crate::drivers::{run_repl, ReplDisplay};
+use crate::games::{Book, Chess};
+use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+use crate::strategy::game_strategy::GameStrategy;
+use shakmaty::uci::Uci;
+use shakmaty::CastlingMode;
+
+/// Parse a move in long algebraic (UCI) notation, e.g. `e2e4`
+/// or `e7e8q`, as a legal move in the given position.
+pub fn parse_uci(chess: &Chess, uci: &str) -> Option<<Chess as GameStrategy>::Move> {
+    let uci: Uci = uci.trim().parse().ok()?;
+    uci.to_move(&chess.inner).ok().map(Some)
+}
+
+/// Describe a move in long algebraic (UCI) notation.
+pub fn move_to_uci(mv: &shakmaty::Move) -> String {
+    Uci::from_move(mv, CastlingMode::Standard).to_string()
+}
+
+/// Play a game of chess as White in a REPL against the engine.
+/// The default depth of 4 should make the
+/// engine reasonably fast.
+pub fn play_chess_against_computer() {
+    play_chess_against_computer_with_depth(4)
+}
+
+/// Play a game of chess as White in a REPL against the engine.
+/// The higher the depth, the longer it takes and
+/// the more accurately the engine performs.
+pub fn play_chess_against_computer_with_depth(depth: i64) {
+    play_chess_against_computer_with_book(depth, &Book::default())
+}
+
+/// Show the board from the maximizer's side,
+/// and moves in long algebraic (UCI) notation.
+impl ReplDisplay for Chess {
+    fn show_board(&self) -> String {
+        self.to_string()
+    }
+
+    fn describe_move(&self, mv: &<Chess as GameStrategy>::Move) -> String {
+        mv.as_ref().map_or_else(String::new, move_to_uci)
+    }
+
+    fn move_prompt(&self) -> String {
+        String::from("Enter a move in UCI notation. (e.g. 'e2e4', or 'e7e8q' to promote) : ")
+    }
+}
+
+/// Play a game of chess as White in a REPL against the engine,
+/// which plays from the opening book while it can.
+pub fn play_chess_against_computer_with_book(depth: i64, book: &Book) {
+    let stdin = std::io::stdin();
+    run_repl(
+        Chess::new(),
+        |input, chess: &Chess| parse_uci(chess, input),
+        |chess: &mut Chess| {
+            chess
+                .book_move(book)
+                .unwrap_or_else(|| chess.get_best_move(depth, true))
+        },
+        stdin.lock(),
+        std::io::stdout(),
+    )
+    .expect("Failed");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use shakmaty::{Role, Square};
+
+    #[test]
+    fn parses_a_pawn_push_from_the_start_position() {
+        let chess = Chess::new();
+        let mv = parse_uci(&chess, "e2e4").unwrap().unwrap();
+        assert_eq!(mv.role(), Role::Pawn);
+        assert_eq!(mv.from(), Some(Square::E2));
+        assert_eq!(mv.to(), Square::E4);
+        assert_eq!(move_to_uci(&mv), "e2e4");
+
+        assert!(parse_uci(&chess, "e2e5").is_none());
+        assert!(parse_uci(&chess, "nonsense").is_none());
+    }
+
+    #[test]
+    fn parses_promotions() {
+        let chess = Chess::from_fen("8/4P3/8/8/8/8/k7/4K3 w - - 0 1").unwrap();
+
+        let queen = parse_uci(&chess, "e7e8q").unwrap().unwrap();
+        assert_eq!(queen.promotion(), Some(Role::Queen));
+        assert_eq!(move_to_uci(&queen), "e7e8q");
+
+        let knight = parse_uci(&chess, "e7e8n").unwrap().unwrap();
+        assert_eq!(knight.promotion(), Some(Role::Knight));
+
+        assert!(parse_uci(&chess, "e7e8").is_none());
+    }
+}
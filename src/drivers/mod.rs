@@ -1,2 +1,107 @@
 mod tic_tac_toe;
 pub use tic_tac_toe::*;
+#[cfg(feature = "chess")]
+mod chess;
+#[cfg(feature = "chess")]
+pub use self::chess::*;
+
+use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+use crate::strategy::game_strategy::{GameResult, GameStrategy};
+use std::io::{BufRead, Write};
+
+/// How a game is shown to someone playing it in a REPL.
+pub trait ReplDisplay: GameStrategy {
+    /// The board as it should be printed.
+    fn show_board(&self) -> String;
+    /// A move as it should be printed.
+    fn describe_move(&self, mv: &Self::Move) -> String;
+    /// What to ask the player for on their turn.
+    fn move_prompt(&self) -> String {
+        match self.board_dimensions() {
+            Some((rows, cols)) => format!("Enter a move on the {}x{} board: ", rows, cols),
+            None => String::from("Enter a move: "),
+        }
+    }
+}
+
+/// Play a game in a REPL against the engine searching `depth`
+/// plies, reading moves from stdin with `parse`. You play as
+/// the maximizer. Entering nothing ends the game early.
+pub fn play_repl<G, P>(game: G, depth: i64, parse: P) -> G
+where
+    G: ReplDisplay,
+    P: Fn(&str, &G) -> Option<G::Move>,
+{
+    let stdin = std::io::stdin();
+    run_repl(
+        game,
+        parse,
+        |game: &mut G| game.get_best_move_for_current_player(depth),
+        stdin.lock(),
+        std::io::stdout(),
+    )
+    .expect("Failed")
+}
+
+/// The loop behind `play_repl`, with the engine picking its
+/// moves with `engine_move`, reading the player's moves from
+/// `input` and writing everything to `output`. Returns the
+/// game as it stood when the REPL ended.
+pub fn run_repl<G, P, E, R, W>(
+    mut game: G,
+    parse: P,
+    mut engine_move: E,
+    mut input: R,
+    mut output: W,
+) -> std::io::Result<G>
+where
+    G: ReplDisplay,
+    P: Fn(&str, &G) -> Option<G::Move>,
+    E: FnMut(&mut G) -> G::Move,
+    R: BufRead,
+    W: Write,
+{
+    loop {
+        writeln!(output, "Board:\n{}", game.show_board())?;
+        writeln!(output, "\n")?;
+
+        if game.is_game_complete() {
+            writeln!(output, "Game is complete.")?;
+            match game.result() {
+                Some(GameResult::Player1Win) => writeln!(output, "You win!")?,
+                Some(GameResult::Player2Win) => writeln!(output, "AI wins!")?,
+                _ => writeln!(output, "Game Tied!")?,
+            }
+            return Ok(game);
+        }
+
+        if !game.current_player() {
+            let move_found = engine_move(&mut game);
+            if !game.is_a_valid_move(&move_found) {
+                writeln!(output, "AI has no move to play.")?;
+                return Ok(game);
+            }
+            writeln!(
+                output,
+                "Move played by AI: {}",
+                game.describe_move(&move_found)
+            )?;
+            game.play(&move_found, false);
+            continue;
+        }
+
+        writeln!(output, "{}", game.move_prompt())?;
+        let mut line = String::new();
+        input.read_line(&mut line)?;
+        if line.trim().is_empty() {
+            return Ok(game);
+        }
+        match parse(line.trim(), &game) {
+            Some(mv) if game.get_available_moves().contains(&mv) => {
+                writeln!(output, "Move played by you: {}", game.describe_move(&mv))?;
+                game.play(&mv, true);
+            }
+            _ => writeln!(output, "Illegal move: {}", line.trim())?,
+        }
+    }
+}
@@ -1,78 +1,234 @@
-use crate::games::TicTacToe;
+use crate::drivers::{run_repl, ReplDisplay};
+use crate::games::{render_grid, ParseBoardError, TicTacToe};
 use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
-use crate::strategy::game_strategy::GameStrategy;
+use crate::strategy::game_strategy::{GameResult, GameStrategy};
+use crate::strategy::self_play::self_play;
+use crate::strategy::zobrist::splitmix64;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
 
-/// Read input.
-fn get_input() -> String {
-    let mut buffer = String::new();
-    std::io::stdin().read_line(&mut buffer).expect("Failed");
-    buffer
+/// How often the engine plays at random on `Difficulty::Easy`.
+const EASY_RANDOM_MOVE_CHANCE: f64 = 0.25;
+
+/// How well the engine plays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Difficulty {
+    /// Looks a single move ahead, and every so
+    /// often plays any legal move at random.
+    Easy,
+    /// Looks a few moves ahead.
+    Medium,
+    /// Searches every line to the end of the game.
+    Hard,
+}
+
+impl FromStr for Difficulty {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "easy" => Ok(Difficulty::Easy),
+            "medium" => Ok(Difficulty::Medium),
+            "hard" => Ok(Difficulty::Hard),
+            _ => Err(format!("Unknown difficulty: {}", s)),
+        }
+    }
+}
+
+impl Difficulty {
+    /// How many plies deep the engine searches a game.
+    pub fn depth(&self, ttt: &TicTacToe) -> i64 {
+        match self {
+            Difficulty::Easy => 1,
+            Difficulty::Medium => 3,
+            Difficulty::Hard => ttt.board.len() as i64,
+        }
+    }
+
+    /// Pick the engine's move, drawing from `rng`
+    /// whenever the difficulty calls for some chance.
+    pub fn pick_move(&self, ttt: &mut TicTacToe, rng: &mut u64) -> usize {
+        let roll = splitmix64(rng) as f64 / u64::MAX as f64;
+        if *self == Difficulty::Easy && roll < EASY_RANDOM_MOVE_CHANCE {
+            let moves = ttt.get_available_moves();
+            if !moves.is_empty() {
+                return moves[(splitmix64(rng) % moves.len() as u64) as usize];
+            }
+        }
+        ttt.get_best_move(self.depth(ttt), true)
+    }
+}
+
+/// Show the board with its rows and columns labelled,
+/// and moves as both their index and their cell.
+impl ReplDisplay for TicTacToe {
+    fn show_board(&self) -> String {
+        render_grid(&self.board, self.cols)
+    }
+
+    fn describe_move(&self, &mv: &usize) -> String {
+        let (row, col) = self.to_coordinates(mv);
+        format!("{} (i.e. {}, {})", mv, row, col)
+    }
+
+    fn move_prompt(&self) -> String {
+        String::from(
+            "Enter a move. (e.g. '1,2' for (row: 1, col: 2), or 'c2' for the same cell) : ",
+        )
+    }
 }
 
 /// Play a game of any size in a REPL against the engine.
 /// The default depth of 6 should make the
 /// engine reasonably fast.
 pub fn play_tic_tac_toe_against_computer(size: usize) {
-    play_tic_tac_toe_against_computer_with_depth(size, 6)
+    play_tic_tac_toe_against_computer_with_depth(size, 6, true)
 }
 
 /// Play a game of any size in a REPL against the engine.
 /// The higher the depth, the longer it takes and
 /// the more accurately the engine performs.
-pub fn play_tic_tac_toe_against_computer_with_depth(size: usize, depth: i64) {
-    let mut ttt = TicTacToe::new(size);
-    loop {
-        println!("Board:\n{}", ttt);
-        println!("\n");
-
-        if ttt.is_game_complete() {
-            println!("Game is complete.");
-            if ttt.is_game_tied() {
-                println!("Game Tied!");
-                break;
-            } else {
-                println!("{} wins!", ttt.get_winner().unwrap());
-                break;
-            }
-        }
+/// You move first if `first_player`, and the engine otherwise.
+pub fn play_tic_tac_toe_against_computer_with_depth(size: usize, depth: i64, first_player: bool) {
+    play_against(size, first_player, |ttt| ttt.get_best_move(depth, true))
+}
 
-        let example_num: i64 = 7;
-        println!(
-            "Enter a move. (e.g. '{}' represents (row: {}, col: {}) : ",
-            example_num,
-            example_num as usize / size,
-            example_num as usize % size
-        );
-        let s = get_input().trim().parse::<usize>();
-        let n = s.unwrap_or(usize::MAX);
+/// Play a game of any size in a REPL against
+/// the engine at the given difficulty.
+/// You move first if `first_player`, and the engine otherwise.
+pub fn play_tic_tac_toe_against_computer_with_difficulty(
+    size: usize,
+    difficulty: Difficulty,
+    first_player: bool,
+) {
+    let mut rng = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64);
+    play_against(size, first_player, |ttt| {
+        difficulty.pick_move(ttt, &mut rng)
+    })
+}
 
-        if n == usize::MAX {
-            break;
-        }
-        println!(
-            "Move played by you: {} (i.e. {}, {})",
-            n,
-            n / size,
-            n % size
-        );
-        ttt.play(&n, true);
-        let move_found = ttt.get_best_move(depth as i64, true);
-        if move_found > (ttt.size * ttt.size) {
-            println!("Game is complete.");
-            if ttt.is_game_tied() {
-                println!("Game Tied!");
-                break;
-            } else {
-                println!("{} wins!", ttt.get_winner().unwrap());
-                break;
-            }
+/// Play a game of TicTacToe out between two engines searching
+/// `depth` plies, the maximizer moving first if `first_player`.
+pub fn self_play_tic_tac_toe(size: usize, depth: i64, first_player: bool) -> Option<GameResult> {
+    let ttt = TicTacToe::new(size).with_first_player(first_player);
+    self_play(ttt, depth, depth)
+}
+
+/// Play a game in a REPL against an engine
+/// that picks its moves with `engine_move`.
+fn play_against<F>(size: usize, first_player: bool, engine_move: F)
+where
+    F: FnMut(&mut TicTacToe) -> usize,
+{
+    let ttt = TicTacToe::new(size).with_first_player(first_player);
+    let stdin = std::io::stdin();
+    run_repl(
+        ttt,
+        |input, ttt: &TicTacToe| ttt.parse_move(input),
+        engine_move,
+        stdin.lock(),
+        std::io::stdout(),
+    )
+    .expect("Failed");
+}
+
+/// Describe the engine's best move in a position given
+/// as rows separated by `/`, e.g. `"xo-/-x-/---"`, for the
+/// maximizer to move if `maximizing`, or else the minimizer.
+pub fn analyze_tic_tac_toe(
+    board: &str,
+    depth: i64,
+    maximizing: bool,
+) -> Result<String, ParseBoardError> {
+    let mut ttt = TicTacToe::from_str_board(&board.replace('/', "\n"))?;
+    if ttt.is_game_complete() {
+        return Ok(format!("The game is over.\n{}", ttt));
+    }
+    // The player to move is the opposite of the one named.
+    let (best_move, score) = ttt.get_best_move_with_score(depth, !maximizing);
+    let (row, col) = ttt.to_coordinates(best_move);
+    Ok(format!(
+        "Best move: {} (i.e. {}, {})\nScore: {}",
+        best_move, row, col, score
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn easy_sometimes_misses_the_winning_move() {
+        // The engine plays 'x', and wins at once with 5.
+        let board = "oo-xx-o--".chars().collect();
+        let ttt = TicTacToe::with_board(board, 3).unwrap();
+
+        let mut rng = 7;
+        let easy: Vec<usize> = (0..32)
+            .map(|_| Difficulty::Easy.pick_move(&mut ttt.clone(), &mut rng))
+            .collect();
+        assert!(easy.contains(&5));
+        assert!(easy.iter().any(|&mv| mv != 5));
+
+        let mut rng = 7;
+        for _ in 0..32 {
+            assert_eq!(Difficulty::Hard.pick_move(&mut ttt.clone(), &mut rng), 5);
         }
-        println!(
-            "Move played by AI: {} (i.e. {}, {})",
-            move_found,
-            move_found / size,
-            move_found % size
+    }
+
+    #[test]
+    fn difficulty_is_parsed_from_its_name() {
+        assert_eq!("Easy".parse(), Ok(Difficulty::Easy));
+        assert_eq!(" hard".parse(), Ok(Difficulty::Hard));
+        assert!("impossible".parse::<Difficulty>().is_err());
+    }
+
+    #[test]
+    fn analyze_finds_the_winning_move() {
+        let analysis = analyze_tic_tac_toe("oo-/xx-/---", 9, true).unwrap();
+        assert!(analysis.starts_with("Best move: 2 (i.e. 0, 2)\n"));
+
+        let analysis = analyze_tic_tac_toe("oo-/xx-/o--", 9, false).unwrap();
+        assert!(analysis.starts_with("Best move: 5 (i.e. 1, 2)\n"));
+
+        let analysis = analyze_tic_tac_toe("ooo/xx-/---", 9, false).unwrap();
+        assert!(analysis.starts_with("The game is over."));
+
+        assert!(analyze_tic_tac_toe("oo/xx-/---", 9, true).is_err());
+    }
+
+    #[test]
+    fn a_scripted_game_is_played_to_the_end() {
+        // Nonsense and taken cells are refused, and the
+        // remaining cells are tried in order until the end.
+        let script = "b2\nnonsense\nb2\na1\nb1\nc1\na2\nc2\na3\nb3\nc3\n";
+        let mut output = vec![];
+        let ttt = run_repl(
+            TicTacToe::new(3),
+            |input, ttt: &TicTacToe| ttt.parse_move(input),
+            |ttt: &mut TicTacToe| ttt.get_best_move_for_current_player(9),
+            script.as_bytes(),
+            &mut output,
+        )
+        .unwrap();
+
+        assert!(ttt.is_game_complete());
+        assert_ne!(ttt.result(), Some(GameResult::Player1Win));
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.contains("Move played by you: 4 (i.e. 1, 1)"));
+        assert!(output.contains("Illegal move: nonsense"));
+        assert!(output.contains("Illegal move: b2"));
+        assert!(
+            output.trim_end().ends_with("Game is complete.\nGame Tied!")
+                || output.trim_end().ends_with("Game is complete.\nAI wins!")
         );
-        ttt.play(&move_found, false);
+    }
+
+    #[test]
+    fn self_play_draws_whoever_moves_first() {
+        assert_eq!(self_play_tic_tac_toe(3, 9, true), Some(GameResult::Draw));
+        assert_eq!(self_play_tic_tac_toe(3, 9, false), Some(GameResult::Draw));
     }
 }
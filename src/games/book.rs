@@ -0,0 +1,97 @@
+use crate::games::Chess;
+use crate::strategy::game_strategy::GameStrategy;
+use shakmaty::uci::Uci;
+use std::collections::HashMap;
+use std::error::Error;
+use std::path::Path;
+
+/// An opening book: the move to play in each known
+/// position, so that the engine needn't search there.
+/// Positions are keyed by their Zobrist hash.
+#[derive(Debug, Clone, Default)]
+pub struct Book {
+    moves: HashMap<u64, Uci>,
+}
+
+impl Book {
+    /// Read a book with one position per line, given as a FEN
+    /// followed by the move to play there in UCI notation, e.g.
+    /// `"rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 e2e4"`.
+    /// Blank lines and lines starting with `#` are skipped.
+    pub fn from_text(text: &str) -> Result<Book, Box<dyn Error>> {
+        let mut book = Book::default();
+        for (line_num, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (fen, uci) = line
+                .rsplit_once(char::is_whitespace)
+                .ok_or_else(|| format!("Line {} has no move: {}", line_num + 1, line))?;
+            let chess = Chess::from_fen(fen.trim())?;
+            let uci: Uci = uci.parse()?;
+            if uci.to_move(&chess.inner).is_err() {
+                return Err(format!("Line {} has an illegal move: {}", line_num + 1, uci).into());
+            }
+            book.moves.insert(chess.position_hash(), uci);
+        }
+        Ok(book)
+    }
+
+    /// Read a book from a file, in the format of `from_text`.
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Book, Box<dyn Error>> {
+        Book::from_text(&std::fs::read_to_string(path)?)
+    }
+
+    /// The number of positions in the book.
+    pub fn len(&self) -> usize {
+        self.moves.len()
+    }
+
+    /// Whether the book holds no positions at all.
+    pub fn is_empty(&self) -> bool {
+        self.moves.is_empty()
+    }
+
+    /// The move the book gives for a position, by its hash.
+    pub fn get(&self, hash: u64) -> Option<&Uci> {
+        self.moves.get(&hash)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BOOK: &str = "
+        # The Ruy Lopez.
+        rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1 e2e4
+        rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1 e7e5
+    ";
+
+    #[test]
+    fn book_gives_its_move_for_the_start_position() {
+        let book = Book::from_text(BOOK).unwrap();
+        assert_eq!(book.len(), 2);
+
+        let mut chess = Chess::new();
+        let mv = chess.book_move(&book).unwrap();
+        assert_eq!(mv.as_ref().unwrap().to(), shakmaty::Square::E4);
+
+        chess.play(&mv, true);
+        assert!(chess.book_move(&book).is_some());
+
+        let maximizer = chess.current_player();
+        let reply = chess.book_move(&book).unwrap();
+        chess.play(&reply, maximizer);
+        assert!(chess.book_move(&book).is_none());
+    }
+
+    #[test]
+    fn book_rejects_illegal_moves() {
+        assert!(Book::from_text("8/8/8/8/8/8/8/8 w - - 0 1 e2e4").is_err());
+        let start = "rnbqkbnr/pppppppp/8/8/8/8/PPPPPPPP/RNBQKBNR w KQkq - 0 1";
+        assert!(Book::from_text(&format!("{} e2e5", start)).is_err());
+        assert!(Book::from_text(start).is_err());
+    }
+}
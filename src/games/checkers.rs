@@ -0,0 +1,464 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use crate::strategy::game_strategy::GameStrategy;
+
+/// The side length of the board.
+const SIZE: usize = 8;
+
+/// A move in Checkers: the squares the piece visits,
+/// starting from where it stands, along with every
+/// square whose piece it jumps over on the way.
+/// A multi-jump is a single move with a longer path.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct CheckersMove {
+    pub path: Vec<usize>,
+    pub captures: Vec<usize>,
+}
+
+/// Whatever is needed to take back a played move.
+#[derive(Debug, Clone)]
+struct Played {
+    mv: CheckersMove,
+    captured: Vec<char>,
+    promoted: bool,
+    maximizer_to_move: bool,
+}
+
+/// A game of Checkers (English draughts) on an 8x8 board.
+///
+/// The maximizer plays the `'b'` men (and `'B'` kings),
+/// starting at the bottom and moving up the board, and
+/// the minimizer plays the `'w'` men (and `'W'` kings)
+/// moving down. Jumps are mandatory, and a man reaching
+/// the far rank is crowned, which ends its move.
+#[derive(Debug, Clone)]
+pub struct Checkers {
+    pub board: Vec<char>,
+    pub default_char: char,
+    /// Whether it is the maximizer's turn to move.
+    pub maximizer_to_move: bool,
+    history: Vec<Played>,
+}
+
+impl Display for Checkers {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.board.chunks(SIZE) {
+            for &x in row.iter() {
+                write!(f, "{}", x)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Checkers {
+    fn default() -> Self {
+        Checkers::new()
+    }
+}
+
+impl Checkers {
+    /// The standard starting position,
+    /// with the maximizer to move.
+    pub fn new() -> Self {
+        let mut board = Self::empty().board;
+        for row in 0..SIZE {
+            for col in 0..SIZE {
+                if (row + col) % 2 == 1 {
+                    if row < 3 {
+                        board[row * SIZE + col] = 'w';
+                    } else if row >= SIZE - 3 {
+                        board[row * SIZE + col] = 'b';
+                    }
+                }
+            }
+        }
+        Self {
+            board,
+            ..Self::empty()
+        }
+    }
+
+    /// A board without any pieces,
+    /// with the maximizer to move.
+    pub fn empty() -> Self {
+        Self {
+            board: vec!['-'; SIZE * SIZE],
+            default_char: '-',
+            maximizer_to_move: true,
+            history: vec![],
+        }
+    }
+
+    fn is_maximizers(piece: char) -> bool {
+        piece == 'b' || piece == 'B'
+    }
+
+    fn is_king(piece: char) -> bool {
+        piece == 'B' || piece == 'W'
+    }
+
+    /// The row directions a piece may move in.
+    fn forward_rows(piece: char) -> &'static [isize] {
+        if Self::is_king(piece) {
+            &[-1, 1]
+        } else if Self::is_maximizers(piece) {
+            &[-1]
+        } else {
+            &[1]
+        }
+    }
+
+    /// The square a given number of diagonal steps away, if on the board.
+    fn step(idx: usize, d_row: isize, d_col: isize, steps: isize) -> Option<usize> {
+        let row = (idx / SIZE) as isize + d_row * steps;
+        let col = (idx % SIZE) as isize + d_col * steps;
+        if (0..SIZE as isize).contains(&row) && (0..SIZE as isize).contains(&col) {
+            Some(row as usize * SIZE + col as usize)
+        } else {
+            None
+        }
+    }
+
+    /// Whether a piece landing on a square gets crowned.
+    fn crowns(piece: char, idx: usize) -> bool {
+        let row = idx / SIZE;
+        match piece {
+            'b' => row == 0,
+            'w' => row == SIZE - 1,
+            _ => false,
+        }
+    }
+
+    /// Extend a jump sequence for `piece` from the end of
+    /// `current` as far as it goes, collecting every
+    /// maximal sequence into `moves`.
+    fn extend_jumps(&self, piece: char, current: &mut CheckersMove, moves: &mut Vec<CheckersMove>) {
+        let from = *current.path.last().unwrap();
+        let start = current.path[0];
+        let mut extended = false;
+
+        if current.path.len() == 1 || !Self::crowns(piece, from) {
+            for &d_row in Self::forward_rows(piece) {
+                for &d_col in [-1, 1].iter() {
+                    let (over, to) = match (
+                        Self::step(from, d_row, d_col, 1),
+                        Self::step(from, d_row, d_col, 2),
+                    ) {
+                        (Some(over), Some(to)) => (over, to),
+                        _ => continue,
+                    };
+                    let jumped = self.board[over];
+                    let is_free = self.board[to] == self.default_char || to == start;
+                    if jumped == self.default_char
+                        || Self::is_maximizers(jumped) == Self::is_maximizers(piece)
+                        || current.captures.contains(&over)
+                        || !is_free
+                    {
+                        continue;
+                    }
+
+                    extended = true;
+                    current.path.push(to);
+                    current.captures.push(over);
+                    self.extend_jumps(piece, current, moves);
+                    current.path.pop();
+                    current.captures.pop();
+                }
+            }
+        }
+
+        if !extended && current.path.len() > 1 {
+            moves.push(current.clone());
+        }
+    }
+
+    /// Every legal move for the given side.
+    fn legal_moves_for(&self, maximizer: bool) -> Vec<CheckersMove> {
+        let mut jumps = vec![];
+        let mut steps = vec![];
+
+        for (idx, &piece) in self.board.iter().enumerate() {
+            if piece == self.default_char || Self::is_maximizers(piece) != maximizer {
+                continue;
+            }
+
+            let mut current = CheckersMove {
+                path: vec![idx],
+                captures: vec![],
+            };
+            self.extend_jumps(piece, &mut current, &mut jumps);
+
+            for &d_row in Self::forward_rows(piece) {
+                for &d_col in [-1, 1].iter() {
+                    if let Some(to) = Self::step(idx, d_row, d_col, 1) {
+                        if self.board[to] == self.default_char {
+                            steps.push(CheckersMove {
+                                path: vec![idx, to],
+                                captures: vec![],
+                            });
+                        }
+                    }
+                }
+            }
+        }
+
+        if jumps.is_empty() {
+            steps
+        } else {
+            jumps
+        }
+    }
+
+    fn piece_value(piece: char) -> f64 {
+        match piece {
+            'b' | 'w' => 1.,
+            'B' | 'W' => 3.,
+            _ => 0.,
+        }
+    }
+}
+
+/// Endow upon Checkers the ability to
+/// play games.
+impl GameStrategy for Checkers {
+    /// The Player is true for the maximizer
+    /// and false for the minimizer.
+    type Player = bool;
+
+    type Move = CheckersMove;
+
+    /// The Board is a single vector of length 64.
+    type Board = Vec<char>;
+
+    /// A finished game scores `WIN_SCORE` or `LOSS_SCORE`, otherwise
+    /// the material balance, with kings worth three men.
+    fn evaluate(&self) -> f64 {
+        match self.get_winner() {
+            Some(true) => Self::WIN_SCORE,
+            Some(false) => Self::LOSS_SCORE,
+            None => self
+                .board
+                .iter()
+                .map(|&piece| {
+                    let value = Self::piece_value(piece) * 10.;
+                    if Self::is_maximizers(piece) {
+                        value
+                    } else {
+                        -value
+                    }
+                })
+                .sum(),
+        }
+    }
+
+    /// A player who can't move loses.
+    fn get_winner(&self) -> Option<Self::Player> {
+        if self.get_available_moves().is_empty() {
+            Some(!self.maximizer_to_move)
+        } else {
+            None
+        }
+    }
+
+    fn is_game_tied(&self) -> bool {
+        false
+    }
+
+    fn is_game_complete(&self) -> bool {
+        self.get_available_moves().is_empty()
+    }
+
+    fn get_available_moves(&self) -> Vec<Self::Move> {
+        self.legal_moves_for(self.maximizer_to_move)
+    }
+
+    fn moves_for(&self, maximizer: bool) -> Vec<Self::Move> {
+        self.legal_moves_for(maximizer)
+    }
+
+    fn play(&mut self, mv: &Self::Move, maximizer: bool) {
+        let (from, to) = match (mv.path.first(), mv.path.last()) {
+            (Some(&from), Some(&to)) => (from, to),
+            _ => panic!("Invalid move. Sentinel?"),
+        };
+
+        let piece = self.board[from];
+        let captured = mv.captures.iter().map(|&idx| self.board[idx]).collect();
+        for &idx in mv.captures.iter() {
+            self.board[idx] = self.default_char;
+        }
+
+        let promoted = Self::crowns(piece, to);
+        self.board[from] = self.default_char;
+        self.board[to] = if promoted {
+            piece.to_ascii_uppercase()
+        } else {
+            piece
+        };
+
+        self.history.push(Played {
+            mv: mv.clone(),
+            captured,
+            promoted,
+            maximizer_to_move: self.maximizer_to_move,
+        });
+        self.maximizer_to_move = !maximizer;
+    }
+
+    fn clear(&mut self, mv: &Self::Move) {
+        if let Some(played) = self.history.pop() {
+            assert_eq!(&played.mv, mv, "Moves must be cleared in reverse order.");
+
+            let (from, to) = (mv.path[0], mv.path[mv.path.len() - 1]);
+            let piece = self.board[to];
+            self.board[to] = self.default_char;
+            self.board[from] = if played.promoted {
+                piece.to_ascii_lowercase()
+            } else {
+                piece
+            };
+
+            for (&idx, &captured) in mv.captures.iter().zip(played.captured.iter()) {
+                self.board[idx] = captured;
+            }
+            self.maximizer_to_move = played.maximizer_to_move;
+        }
+    }
+
+    /// Set up the standard starting position again.
+    fn reset(&mut self) {
+        *self = Checkers::new();
+    }
+
+    fn current_player(&self) -> bool {
+        self.maximizer_to_move
+    }
+
+    fn get_board(&self) -> &Self::Board {
+        &self.board
+    }
+
+    fn board_dimensions(&self) -> Option<(usize, usize)> {
+        Some((SIZE, SIZE))
+    }
+
+    fn is_a_valid_move(&self, mv: &Self::Move) -> bool {
+        self.get_available_moves().contains(mv)
+    }
+
+    fn get_a_sentinel_move(&self) -> Self::Move {
+        CheckersMove::default()
+    }
+
+    fn position_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.board.hash(&mut hasher);
+        self.maximizer_to_move.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Try the longest jumps first.
+    fn order_moves(&self, mut moves: Vec<Self::Move>, _is_maximizing: bool) -> Vec<Self::Move> {
+        moves.sort_by_key(|mv| std::cmp::Reverse(mv.captures.len()));
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+
+    #[test]
+    fn opening_has_seven_moves() {
+        let game = Checkers::new();
+        assert_eq!(game.get_available_moves().len(), 7);
+        assert_eq!(game.evaluate(), 0.);
+    }
+
+    #[test]
+    fn single_jump_is_forced() {
+        let mut game = Checkers::empty();
+        game.board[42] = 'b';
+        game.board[46] = 'b';
+        game.board[35] = 'w';
+
+        let moves = game.get_available_moves();
+        assert_eq!(
+            moves,
+            vec![CheckersMove {
+                path: vec![42, 28],
+                captures: vec![35],
+            }]
+        );
+
+        let before = game.board.clone();
+        game.play(&moves[0], true);
+        assert_eq!(game.board[35], '-');
+        assert_eq!(game.board[28], 'b');
+        assert!(game.is_game_complete());
+        assert_eq!(game.get_winner(), Some(true));
+
+        game.clear(&moves[0]);
+        assert_eq!(game.board, before);
+        assert!(game.maximizer_to_move);
+    }
+
+    #[test]
+    fn double_jump_is_a_single_move() {
+        let mut game = Checkers::empty();
+        game.board[42] = 'b';
+        game.board[35] = 'w';
+        game.board[21] = 'W';
+
+        let moves = game.get_available_moves();
+        assert_eq!(
+            moves,
+            vec![CheckersMove {
+                path: vec![42, 28, 14],
+                captures: vec![35, 21],
+            }]
+        );
+
+        let before = game.board.clone();
+        game.play(&moves[0], true);
+        assert_eq!(game.board.iter().filter(|&&x| x != '-').count(), 1);
+
+        game.clear(&moves[0]);
+        assert_eq!(game.board, before);
+    }
+
+    #[test]
+    fn reaching_the_far_rank_crowns() {
+        let mut game = Checkers::empty();
+        game.board[10] = 'b';
+        game.board[60] = 'w';
+
+        let mv = CheckersMove {
+            path: vec![10, 1],
+            captures: vec![],
+        };
+        assert!(game.is_a_valid_move(&mv));
+        game.play(&mv, true);
+        assert_eq!(game.board[1], 'B');
+
+        game.clear(&mv);
+        assert_eq!(game.board[10], 'b');
+        assert_eq!(game.board[1], '-');
+    }
+
+    #[test]
+    fn engine_takes_the_king() {
+        let mut game = Checkers::empty();
+        game.board[42] = 'b';
+        game.board[35] = 'W';
+        game.board[3] = 'w';
+
+        let mv = game.get_best_move(3, false);
+        assert_eq!(mv.captures, vec![35]);
+    }
+}
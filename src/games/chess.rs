@@ -1,23 +1,128 @@
-use crate::strategy::game_strategy::GameStrategy;
+use crate::games::Book;
+use crate::strategy::game_strategy::{GameStrategy, UndoError};
 use anyhow::{bail, Result};
+use std::cell::{Cell, OnceCell};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fmt::Display;
 use std::ops::{Deref, DerefMut};
 
+use shakmaty::fen::Fen;
+use shakmaty::san::SanPlus;
+use shakmaty::zobrist::ZobristHash;
 #[cfg(feature = "chess")]
 pub use shakmaty::Chess as ShakmatyChess;
-use shakmaty::Position;
+use shakmaty::{CastlingMode, EnPassantMode, FromSetup, Position};
+
+/// The factor by which raw piece values are multiplied
+/// so that material scores sit well inside the `±1000`
+/// range reserved for decisive outcomes.
+const MATERIAL_SCALE: f64 = 10.;
+
+/// The standard material value of a piece, in pawns.
+fn piece_value(role: shakmaty::Role) -> f64 {
+    match role {
+        shakmaty::Role::Pawn => 1.,
+        shakmaty::Role::Knight => 3.,
+        shakmaty::Role::Bishop => 3.,
+        shakmaty::Role::Rook => 5.,
+        shakmaty::Role::Queen => 9.,
+        shakmaty::Role::King => 0.,
+    }
+}
+
+/// White's material less Black's, scaled, counted
+/// by going over every piece on the board.
+fn count_material(board: &shakmaty::Board) -> f64 {
+    board
+        .clone()
+        .into_iter()
+        .map(|(_, piece)| {
+            let value = piece_value(piece.role) * MATERIAL_SCALE;
+            match piece.color {
+                shakmaty::Color::White => value,
+                shakmaty::Color::Black => -value,
+            }
+        })
+        .sum()
+}
+
+/// How much material a move gains for White, scaled,
+/// through what it captures and what it promotes to.
+fn material_gain(mv: &shakmaty::Move, mover: shakmaty::Color) -> f64 {
+    let captured = mv.capture().map_or(0., piece_value);
+    let promoted = mv.promotion().map_or(0., |role| {
+        piece_value(role) - piece_value(shakmaty::Role::Pawn)
+    });
+    let gain = (captured + promoted) * MATERIAL_SCALE;
+    match mover {
+        shakmaty::Color::White => gain,
+        shakmaty::Color::Black => -gain,
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct Chess {
     pub inner: ShakmatyChess,
     pub moves_played: shakmaty::MoveList,
+    /// The positions prior to each move in `moves_played`,
+    /// so that any ply can be reverted by restoring its snapshot.
+    pub history: Vec<ShakmatyChess>,
+    /// How many times each position, by its Zobrist hash,
+    /// has occurred in the game so far.
+    pub repetitions: HashMap<u64, usize>,
+    /// The side the engine treats as the maximizer.
+    pub maximizer_color: shakmaty::Color,
+    /// The legal moves in the current position, generated
+    /// on first request and dropped whenever the position changes.
+    legal_moves: OnceCell<Vec<Option<shakmaty::Move>>>,
+    /// White's material less Black's, counted on the first
+    /// evaluation and kept up to date as moves are played
+    /// and taken back. Changing the position through
+    /// `DerefMut` has it counted afresh.
+    material: Cell<Option<f64>>,
 }
 
 impl Default for Chess {
     fn default() -> Self {
-        Self {
-            inner: ShakmatyChess::default(),
-            moves_played: shakmaty::MoveList::default(),
+        Self::from_position(ShakmatyChess::default())
+    }
+}
+
+/// The board from the maximizer's side, one rank per line,
+/// with White's pieces in uppercase and Black's in lowercase.
+impl Display for Chess {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let flipped = self.maximizer_color == shakmaty::Color::Black;
+        let ranks: Vec<u32> = if flipped {
+            (0..8).collect()
+        } else {
+            (0..8).rev().collect()
+        };
+        let files: Vec<u32> = if flipped {
+            (0..8).rev().collect()
+        } else {
+            (0..8).collect()
+        };
+
+        for &rank in ranks.iter() {
+            write!(f, "{}", rank + 1)?;
+            for &file in files.iter() {
+                let square = shakmaty::Square::new(rank * 8 + file);
+                let ch = self
+                    .inner
+                    .board()
+                    .piece_at(square)
+                    .map_or('.', |piece| piece.char());
+                write!(f, " {}", ch)?;
+            }
+            writeln!(f)?;
+        }
+        write!(f, " ")?;
+        for &file in files.iter() {
+            write!(f, " {}", (b'a' + file as u8) as char)?;
         }
+        writeln!(f)
     }
 }
 
@@ -31,6 +136,9 @@ impl Deref for Chess {
 
 impl DerefMut for Chess {
     fn deref_mut(&mut self) -> &mut Self::Target {
+        // The position may be changed through the reference.
+        self.legal_moves = OnceCell::new();
+        self.material.set(None);
         &mut self.inner
     }
 }
@@ -40,8 +148,166 @@ impl Chess {
         Self::default()
     }
 
-    fn _undo(&self, _move: shakmaty::Move) -> Result<()> {
-        todo!("Implement undo for Chess moves.");
+    /// Play with the given side as the maximizer,
+    /// so that scores are from its point of view.
+    pub fn with_maximizer(self, color: shakmaty::Color) -> Self {
+        Self {
+            maximizer_color: color,
+            ..self
+        }
+    }
+
+    /// The side that plays as the maximizer if
+    /// `maximizer` is true, or the minimizer otherwise.
+    fn color_of(&self, maximizer: bool) -> shakmaty::Color {
+        if maximizer {
+            self.maximizer_color
+        } else {
+            !self.maximizer_color
+        }
+    }
+
+    /// Start a game from a given position.
+    fn from_position(inner: ShakmatyChess) -> Self {
+        let mut chess = Self {
+            inner,
+            moves_played: shakmaty::MoveList::default(),
+            history: Vec::new(),
+            repetitions: HashMap::new(),
+            maximizer_color: shakmaty::Color::White,
+            legal_moves: OnceCell::new(),
+            material: Cell::new(None),
+        };
+        chess.repetitions.insert(chess.position_hash(), 1);
+        chess
+    }
+
+    /// Whether the side to move is in check.
+    pub fn is_check(&self) -> bool {
+        self.inner.is_check()
+    }
+
+    /// Whether the side to move is in check and has no legal moves.
+    pub fn is_checkmate(&self) -> bool {
+        self.inner.is_checkmate()
+    }
+
+    /// Whether the side to move has no legal moves but isn't in check.
+    pub fn is_stalemate(&self) -> bool {
+        self.inner.is_stalemate()
+    }
+
+    /// Whether the current position has occurred
+    /// at least three times, which makes the game a draw.
+    pub fn is_threefold_repetition(&self) -> bool {
+        matches!(self.repetitions.get(&self.position_hash()), Some(&count) if count >= 3)
+    }
+
+    /// Whether fifty moves by each side have passed without
+    /// a capture or a pawn move, which makes the game a draw.
+    pub fn is_fifty_move_draw(&self) -> bool {
+        self.inner.halfmoves() >= 100
+    }
+
+    /// Whether the game is drawn by a rule
+    /// rather than by the position itself.
+    fn is_drawn_by_rule(&self) -> bool {
+        self.is_threefold_repetition() || self.is_fifty_move_draw()
+    }
+
+    /// The move the opening book gives for the
+    /// current position, if it's in the book.
+    pub fn book_move(&self, book: &Book) -> Option<<Chess as GameStrategy>::Move> {
+        let uci = book.get(self.position_hash())?;
+        uci.to_move(&self.inner).ok().map(Some)
+    }
+
+    /// Count the leaf nodes of the game tree `depth` plies
+    /// deep, the standard check on move generation.
+    pub fn perft(&mut self, depth: u32) -> u64 {
+        if depth == 0 {
+            return 1;
+        }
+        let maximizer = self.current_player();
+        let mut nodes = 0;
+        for mv in self.get_available_moves() {
+            self.play(&mv, maximizer);
+            nodes += self.perft(depth - 1);
+            self.undo().expect("Couldn't undo a move just played.");
+        }
+        nodes
+    }
+
+    /// Set up a game from the position described by a FEN,
+    /// e.g. `"rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1"`.
+    pub fn from_fen(fen: &str) -> std::result::Result<Chess, Box<dyn Error>> {
+        let fen: Fen = fen.parse()?;
+        let inner: ShakmatyChess = fen.into_position(CastlingMode::Standard)?;
+        Ok(Self::from_position(inner))
+    }
+
+    /// Describe the current position as a FEN.
+    pub fn to_fen(&self) -> String {
+        Fen::from_position(self.inner.clone(), EnPassantMode::Legal).to_string()
+    }
+
+    /// Describe the moves played so far in PGN movetext,
+    /// e.g. `"1. e4 e5 2. Qh5 *"`, followed by the result.
+    /// Recorded moves are replayed from the position before
+    /// the first one, and the text stops short at the first
+    /// move that isn't legal there.
+    pub fn to_pgn(&self) -> String {
+        let mut position = self
+            .history
+            .first()
+            .cloned()
+            .unwrap_or_else(|| self.inner.clone());
+        let mut tokens = vec![];
+        let mut complete = self.history.len() == self.moves_played.len();
+
+        for (ply, mv) in self.moves_played.iter().enumerate() {
+            if !position.is_legal(mv) {
+                complete = false;
+                break;
+            }
+            if position.turn() == shakmaty::Color::White {
+                tokens.push(format!("{}.", position.fullmoves()));
+            } else if ply == 0 {
+                tokens.push(format!("{}...", position.fullmoves()));
+            }
+            tokens.push(SanPlus::from_move_and_play_unchecked(&mut position, mv).to_string());
+        }
+
+        let result = match self.outcome() {
+            Some(_) if !complete => "*",
+            Some(shakmaty::Outcome::Decisive {
+                winner: shakmaty::Color::White,
+            }) => "1-0",
+            Some(shakmaty::Outcome::Decisive {
+                winner: shakmaty::Color::Black,
+            }) => "0-1",
+            Some(shakmaty::Outcome::Draw) => "1/2-1/2",
+            None => "*",
+        };
+        tokens.push(result.to_string());
+        tokens.join(" ")
+    }
+
+    /// Restore the position that was current before `_move` was played.
+    fn _undo(&mut self, _move: shakmaty::Move) -> Result<()> {
+        if let Some(prev_position) = self.history.pop() {
+            if let Some(count) = self.repetitions.get_mut(&self.position_hash()) {
+                *count -= 1;
+            }
+            if let Some(material) = self.material.get_mut() {
+                *material -= material_gain(&_move, prev_position.turn());
+            }
+            self.inner = prev_position;
+            self.legal_moves = OnceCell::new();
+            Ok(())
+        } else {
+            bail!("No position to restore for move: {:?}", _move);
+        }
     }
 
     pub fn undo(&mut self) -> Result<()> {
@@ -53,8 +319,14 @@ impl Chess {
     }
 
     fn _play(&mut self, _move: shakmaty::Move) {
+        self.history.push(self.inner.clone());
+        if let Some(material) = self.material.get_mut() {
+            *material += material_gain(&_move, self.inner.turn());
+        }
         self.inner.play_unchecked(&_move);
         self.moves_played.push(_move);
+        self.legal_moves = OnceCell::new();
+        *self.repetitions.entry(self.position_hash()).or_insert(0) += 1;
     }
 }
 
@@ -71,26 +343,75 @@ impl GameStrategy for Chess {
         mv.is_some()
     }
 
+    fn position_hash(&self) -> u64 {
+        self.inner.zobrist_hash::<u64>()
+    }
+
     fn play(&mut self, mv: &Self::Move, maximizer: bool) {
         if let Some(_mv) = mv {
-            if maximizer {
-                assert!(self.inner.turn() == shakmaty::Color::White);
-                // self.inner.play(&_mv);
-                self._play(_mv.clone());
-                self.moves_played.push(_mv.clone());
-            } else {
-                assert!(self.inner.turn() == shakmaty::Color::Black);
-                // self.inner.play(&mv);
-                self._play(_mv.clone());
-                self.moves_played.push(_mv.clone());
-            }
+            assert!(
+                self.inner.turn() == self.color_of(maximizer),
+                "It is {:?}'s turn to move.",
+                self.inner.turn()
+            );
+            self._play(_mv.clone());
         } else {
             panic!("Invalid move. Sentinel?");
         }
     }
 
+    /// Material balance from the maximizer's point of view,
+    /// scaled so that a full army is worth a few hundred points and a
+    /// checkmate sits at `WIN_SCORE` or `LOSS_SCORE`. The balance is
+    /// kept up to date move by move rather than recounted each time.
     fn evaluate(&self) -> f64 {
-        todo!("Implement a static evaluation of a chess position.")
+        if let Some(outcome) = self.outcome() {
+            return match outcome {
+                shakmaty::Outcome::Decisive { winner } if winner == self.maximizer_color => {
+                    Self::WIN_SCORE
+                }
+                shakmaty::Outcome::Decisive { .. } => Self::LOSS_SCORE,
+                shakmaty::Outcome::Draw => 0.,
+            };
+        }
+        if self.is_drawn_by_rule() {
+            return 0.;
+        }
+
+        let material = self.material.get().unwrap_or_else(|| {
+            let material = count_material(self.inner.board());
+            self.material.set(Some(material));
+            material
+        });
+        match self.maximizer_color {
+            shakmaty::Color::White => material,
+            shakmaty::Color::Black => -material,
+        }
+    }
+
+    /// A position is quiet once no captures or promotions are left.
+    fn is_quiet(&self) -> bool {
+        self.get_noisy_moves().is_empty()
+    }
+
+    /// The captures and promotions among the legal moves.
+    fn get_noisy_moves(&self) -> Vec<Self::Move> {
+        self.get_available_moves()
+            .into_iter()
+            .filter(|mv| matches!(mv, Some(mv) if mv.is_capture() || mv.is_promotion()))
+            .collect()
+    }
+
+    /// Captures by how much the victim is worth, and among
+    /// those with the same victim, by how little the attacker
+    /// is, so that e.g. a pawn taking a queen comes first.
+    fn score_move(&self, mv: &Self::Move) -> i32 {
+        match mv.as_ref().and_then(|mv| Some((mv.capture()?, mv.role()))) {
+            Some((victim, attacker)) => {
+                (piece_value(victim) * MATERIAL_SCALE - piece_value(attacker)) as i32
+            }
+            None => 0,
+        }
     }
 
     fn clear(&mut self, mv: &Self::Move) {
@@ -107,16 +428,73 @@ impl GameStrategy for Chess {
             .expect(&format!("Couldn't undo move: {:#?}", _mv));
     }
 
+    fn undo_move(&mut self) -> std::result::Result<(), UndoError> {
+        self.undo().map_err(|_| UndoError::NothingToUndo)
+    }
+
+    /// Generated once per position, and
+    /// cached until the position changes.
     fn get_available_moves(&self) -> Vec<Self::Move> {
-        self.legal_moves()
-            .iter()
-            .map(|mv| Some(mv.clone()))
-            .collect()
+        self.legal_moves
+            .get_or_init(|| {
+                self.inner
+                    .legal_moves()
+                    .iter()
+                    .map(|mv| Some(mv.clone()))
+                    .collect()
+            })
+            .clone()
+    }
+
+    /// The side not to move has its moves found by handing
+    /// it the turn, which is impossible while it gives check.
+    fn moves_for(&self, maximizer: bool) -> Vec<Self::Move> {
+        if maximizer == self.current_player() {
+            return self.get_available_moves();
+        }
+        let mut setup = self.inner.clone().into_setup(EnPassantMode::Legal);
+        setup.turn = !setup.turn;
+        setup.ep_square = None;
+        match ShakmatyChess::from_setup(setup, CastlingMode::Standard) {
+            Ok(swapped) => swapped
+                .legal_moves()
+                .iter()
+                .map(|mv| Some(mv.clone()))
+                .collect(),
+            Err(_) => vec![],
+        }
+    }
+
+    /// Set up the standard starting position again,
+    /// forgetting every move played.
+    /// Take back every move played, returning to the
+    /// position the game was set up from, e.g. by a FEN.
+    fn reset(&mut self) {
+        let start = self
+            .history
+            .first()
+            .cloned()
+            .unwrap_or_else(|| self.inner.clone());
+        *self = Chess::from_position(start).with_maximizer(self.maximizer_color);
+    }
+
+    fn current_player(&self) -> bool {
+        self.inner.turn() == self.maximizer_color
+    }
+
+    /// The plies played since this game was set up,
+    /// not counting any before the position it started from.
+    fn ply(&self) -> usize {
+        self.moves_played.len()
     }
 
     fn get_board(&self) -> &Self::Board {
         &self.inner.board()
     }
+
+    fn board_dimensions(&self) -> Option<(usize, usize)> {
+        Some((8, 8))
+    }
     fn get_winner(&self) -> Option<Self::Player> {
         if let Some(outcome) = self.outcome() {
             match outcome {
@@ -129,7 +507,7 @@ impl GameStrategy for Chess {
     }
 
     fn is_game_complete(&self) -> bool {
-        self.outcome().is_some()
+        self.outcome().is_some() || self.is_drawn_by_rule()
     }
 
     fn is_game_tied(&self) -> bool {
@@ -139,7 +517,7 @@ impl GameStrategy for Chess {
                 _ => false,
             }
         } else {
-            false
+            self.is_drawn_by_rule()
         }
     }
 }
@@ -147,6 +525,7 @@ impl GameStrategy for Chess {
 #[cfg(test)]
 pub mod tests {
     pub use super::Chess;
+    use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
     pub use crate::strategy::game_strategy::GameStrategy;
     use shakmaty::{
         CastlingMode, Chess as ChessGame, Color, FromSetup, Piece, Position, Role, Setup, Square,
@@ -164,6 +543,61 @@ pub mod tests {
         assert_eq!(chess.evaluate(), 0.);
     }
 
+    #[test]
+    fn test_chess_evaluate_material_advantage() {
+        let mut setup = Setup::default();
+        setup.board.remove_piece_at(Square::D8).unwrap();
+        let chess = Chess {
+            inner: ChessGame::from_setup(setup, CastlingMode::Standard).unwrap(),
+            ..Chess::default()
+        };
+        assert_eq!(chess.evaluate(), 90.);
+    }
+
+    #[test]
+    fn test_chess_undo_restores_start_position() {
+        let mut chess = Chess::new();
+        let e4 = chess
+            .get_available_moves()
+            .into_iter()
+            .find(|mv| mv.as_ref().unwrap().to() == Square::E4)
+            .unwrap();
+
+        chess.play(&e4, true);
+        assert_eq!(chess.turn(), Color::Black);
+
+        chess.undo().unwrap();
+        let start = ChessGame::default();
+        assert_eq!(chess.board(), start.board());
+        assert_eq!(chess.turn(), start.turn());
+        assert!(chess.history.is_empty());
+    }
+
+    #[test]
+    fn test_chess_play_records_a_single_move() {
+        let mut chess = Chess::new();
+        let mv = chess.get_available_moves().remove(0);
+        chess.play(&mv, true);
+        assert_eq!(chess.moves_played.len(), 1);
+        assert_eq!(chess.history.len(), 1);
+    }
+
+    #[test]
+    fn test_chess_ply_counts_moves_played_and_undone() {
+        let mut chess = Chess::new();
+        assert_eq!(chess.ply(), 0);
+        let mv = chess.get_available_moves().remove(0);
+        chess.play(&mv, true);
+        assert_eq!(chess.ply(), 1);
+        let reply = chess.get_available_moves().remove(0);
+        chess.play(&reply, false);
+        assert_eq!(chess.ply(), 2);
+        chess.clear(&reply);
+        assert_eq!(chess.ply(), 1);
+        chess.undo().unwrap();
+        assert_eq!(chess.ply(), 0);
+    }
+
     #[test]
     fn test_chess_available_moves() {
         let chess = Chess::new();
@@ -203,4 +637,364 @@ pub mod tests {
         assert_eq!(moves.len(), 1);
         // println!("{moves:#?}");
     }
+
+    #[test]
+    fn test_chess_fen_round_trip() {
+        let fen = "rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1";
+        let chess = Chess::from_fen(fen).unwrap();
+        assert_eq!(chess.turn(), Color::Black);
+        assert!(chess.moves_played.is_empty());
+        assert_eq!(chess.to_fen(), fen);
+
+        assert!(Chess::from_fen("not a fen").is_err());
+    }
+
+    #[test]
+    fn test_chess_finds_mate_in_one() {
+        let mut chess = Chess::from_fen("6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1").unwrap();
+        let best_move = chess.get_best_move(1, false);
+        assert_eq!(best_move.as_ref().unwrap().to(), Square::A8);
+
+        chess.play(&best_move, true);
+        assert!(chess.is_checkmate());
+    }
+
+    fn play_san(chess: &mut Chess, san: &str) {
+        let mv = san
+            .parse::<shakmaty::san::San>()
+            .unwrap()
+            .to_move(&chess.inner)
+            .unwrap();
+        let maximizer = chess.current_player();
+        chess.play(&Some(mv), maximizer);
+    }
+
+    #[test]
+    fn test_chess_to_pgn_scholars_mate() {
+        let mut chess = Chess::new();
+        assert_eq!(chess.to_pgn(), "*");
+
+        for san in ["e4", "e5", "Bc4", "Nc6", "Qh5", "Nf6", "Qxf7#"] {
+            play_san(&mut chess, san);
+        }
+        assert_eq!(
+            chess.to_pgn(),
+            "1. e4 e5 2. Bc4 Nc6 3. Qh5 Nf6 4. Qxf7# 1-0"
+        );
+    }
+
+    #[test]
+    fn test_chess_to_pgn_from_a_black_to_move_position() {
+        let mut chess =
+            Chess::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1").unwrap();
+        play_san(&mut chess, "e5");
+        play_san(&mut chess, "Nf3");
+        assert_eq!(chess.to_pgn(), "1... e5 2. Nf3 *");
+    }
+
+    #[test]
+    fn test_chess_to_pgn_stops_at_a_corrupted_move() {
+        let mut chess = Chess::new();
+        play_san(&mut chess, "e4");
+        let e4 = chess.moves_played[0].clone();
+        chess.moves_played.push(e4);
+        assert_eq!(chess.to_pgn(), "1. e4 *");
+    }
+
+    #[test]
+    fn test_chess_quiescence_avoids_hanging_the_queen() {
+        // The pawn on d5 is defended by the one on e6.
+        let mut chess = Chess::from_fen("6k1/8/4p3/3p4/8/8/P7/3Q2K1 w - - 0 1").unwrap();
+        let qxd5 = find_move(&chess, Square::D1, Square::D5);
+
+        // A search that stops right after Qxd5 sees a free pawn...
+        chess.play(&qxd5, true);
+        assert_eq!(chess.evaluate(), 90.);
+        // ...but not once the recapture is played out.
+        assert_eq!(
+            chess.quiescence(f64::NEG_INFINITY, f64::INFINITY, false),
+            0.
+        );
+        chess.undo().unwrap();
+
+        let best_move = chess.get_best_move(0, false);
+        assert_ne!(best_move, qxd5);
+    }
+
+    fn find_move(chess: &Chess, from: Square, to: Square) -> Option<shakmaty::Move> {
+        chess
+            .get_available_moves()
+            .into_iter()
+            .flatten()
+            .find(|mv| mv.from() == Some(from) && mv.to() == to)
+    }
+
+    #[test]
+    fn test_chess_threefold_repetition_is_a_draw() {
+        // White is a rook up, so Black is happy to repeat.
+        let mut chess = Chess::from_fen("1r2k3/8/8/8/8/8/8/R3K2R w - - 0 1").unwrap();
+        let shuffle = [
+            (Square::E1, Square::D1),
+            (Square::E8, Square::D8),
+            (Square::D1, Square::E1),
+            (Square::D8, Square::E8),
+        ];
+        for &(from, to) in shuffle.iter().chain(shuffle[..3].iter()) {
+            let mv = find_move(&chess, from, to);
+            let maximizer = chess.turn() == Color::White;
+            chess.play(&mv, maximizer);
+        }
+        assert!(!chess.is_game_complete());
+        assert_eq!(chess.evaluate(), 50.);
+
+        // Returning to the start for the third time draws.
+        let best_move = chess.get_best_move(0, true);
+        assert_eq!(best_move, find_move(&chess, Square::D8, Square::E8));
+
+        chess.play(&best_move, false);
+        assert!(chess.is_threefold_repetition());
+        assert!(chess.is_game_complete());
+        assert!(chess.is_game_tied());
+        assert_eq!(chess.evaluate(), 0.);
+
+        chess.undo().unwrap();
+        assert!(!chess.is_threefold_repetition());
+    }
+
+    #[test]
+    fn test_chess_reset_returns_to_the_start() {
+        let mut chess = Chess::new();
+        play_san(&mut chess, "e4");
+        play_san(&mut chess, "e5");
+        chess.reset();
+        assert_eq!(chess.to_fen(), Chess::new().to_fen());
+        assert!(chess.undo().is_err());
+
+        let fen = "6k1/5ppp/8/8/8/8/8/R5K1 w - - 0 1";
+        let mut chess = Chess::from_fen(fen).unwrap();
+        play_san(&mut chess, "Ra8#");
+        chess.reset();
+        assert_eq!(chess.to_fen(), fen);
+        assert!(chess.moves_played.is_empty());
+    }
+
+    #[test]
+    fn test_chess_available_moves_are_cached_per_position() {
+        let mut chess = Chess::new();
+        let first = chess.get_available_moves();
+        assert!(chess.legal_moves.get().is_some());
+        assert_eq!(chess.get_available_moves(), first);
+
+        play_san(&mut chess, "e4");
+        assert!(chess.legal_moves.get().is_none());
+        let after = chess.get_available_moves();
+        assert_eq!(after.len(), chess.inner.legal_moves().len());
+        assert_ne!(after, first);
+
+        chess.undo().unwrap();
+        assert_eq!(chess.get_available_moves(), first);
+    }
+
+    #[test]
+    fn test_chess_perft_from_the_start() {
+        let mut chess = Chess::new();
+        assert_eq!(chess.perft(1), 20);
+        assert_eq!(chess.perft(2), 400);
+        assert_eq!(chess.perft(3), 8902);
+        assert_eq!(chess.to_fen(), Chess::new().to_fen());
+    }
+
+    #[test]
+    fn test_chess_check_checkmate_and_stalemate() {
+        let check = Chess::from_fen("4k3/8/8/8/8/8/8/4R1K1 b - - 0 1").unwrap();
+        assert!(check.is_check());
+        assert!(!check.is_checkmate());
+        assert!(!check.is_stalemate());
+
+        let checkmate = Chess::from_fen("R5k1/5ppp/8/8/8/8/8/6K1 b - - 0 1").unwrap();
+        assert!(checkmate.is_check());
+        assert!(checkmate.is_checkmate());
+        assert!(!checkmate.is_stalemate());
+
+        let stalemate = Chess::from_fen("7k/5Q2/6K1/8/8/8/8/8 b - - 0 1").unwrap();
+        assert!(!stalemate.is_check());
+        assert!(!stalemate.is_checkmate());
+        assert!(stalemate.is_stalemate());
+        assert!(stalemate.is_game_tied());
+    }
+
+    #[test]
+    fn test_chess_current_player_follows_the_turn() {
+        let mut chess = Chess::new();
+        assert!(chess.current_player());
+        play_san(&mut chess, "e4");
+        assert!(!chess.current_player());
+
+        let black_to_move =
+            Chess::from_fen("rnbqkbnr/pppppppp/8/8/4P3/8/PPPP1PPP/RNBQKBNR b KQkq - 0 1").unwrap();
+        assert!(!black_to_move.current_player());
+    }
+
+    #[test]
+    fn test_chess_engine_plays_black() {
+        let mut chess = Chess::new().with_maximizer(Color::Black);
+        assert!(!chess.current_player());
+        play_san(&mut chess, "e4");
+        assert!(chess.current_player());
+
+        let best_move = chess.get_best_move_for_current_player(2);
+        assert_eq!(chess.turn(), Color::Black);
+        assert!(chess.inner.is_legal(best_move.as_ref().unwrap()));
+
+        chess.play(&best_move, true);
+        assert_eq!(chess.turn(), Color::White);
+    }
+
+    #[test]
+    fn test_chess_evaluates_from_the_maximizer_side() {
+        let fen = "4k3/8/8/8/8/8/8/3QK3 w - - 0 1";
+        let white = Chess::from_fen(fen).unwrap();
+        let black = Chess::from_fen(fen).unwrap().with_maximizer(Color::Black);
+        assert!(white.evaluate() > 0.);
+        assert_eq!(black.evaluate(), -white.evaluate());
+    }
+
+    #[test]
+    fn test_chess_fifty_move_rule_is_a_draw() {
+        let mut chess = Chess::from_fen("4k3/8/8/8/8/8/8/R3K3 w - - 99 80").unwrap();
+        assert!(!chess.is_fifty_move_draw());
+        assert!(!chess.is_game_complete());
+
+        play_san(&mut chess, "Ra2");
+        assert!(chess.is_fifty_move_draw());
+        assert!(chess.is_game_complete());
+        assert!(chess.is_game_tied());
+        assert_eq!(chess.evaluate(), 0.);
+
+        // A pawn move resets the clock.
+        let mut chess = Chess::from_fen("4k3/8/8/8/8/8/P7/R3K3 w - - 99 80").unwrap();
+        play_san(&mut chess, "a3");
+        assert!(!chess.is_game_complete());
+    }
+
+    #[test]
+    fn test_chess_moves_for_either_side() {
+        let mut chess = Chess::new();
+        assert_eq!(chess.moves_for(true), chess.get_available_moves());
+        assert_eq!(chess.moves_for(false).len(), 20);
+
+        play_san(&mut chess, "e4");
+        // With the e-pawn gone, the queen and bishop have room.
+        assert_eq!(chess.moves_for(true).len(), 30);
+        assert_eq!(chess.moves_for(false), chess.get_available_moves());
+
+        // White can't be handed the turn while giving check.
+        let check = Chess::from_fen("4k3/8/8/8/8/8/8/4R1K1 b - - 0 1").unwrap();
+        assert!(check.moves_for(true).is_empty());
+        assert!(!check.moves_for(false).is_empty());
+    }
+
+    #[test]
+    fn test_chess_displays_the_board() {
+        let chess = Chess::new();
+        let rendered = chess.to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        assert_eq!(
+            lines[..8],
+            [
+                "8 r n b q k b n r",
+                "7 p p p p p p p p",
+                "6 . . . . . . . .",
+                "5 . . . . . . . .",
+                "4 . . . . . . . .",
+                "3 . . . . . . . .",
+                "2 P P P P P P P P",
+                "1 R N B Q K B N R",
+            ]
+        );
+        assert_eq!(lines[8], "  a b c d e f g h");
+
+        let flipped = Chess::new().with_maximizer(Color::Black).to_string();
+        let lines: Vec<&str> = flipped.lines().collect();
+        assert_eq!(lines[0], "1 R N B K Q B N R");
+        assert_eq!(lines[7], "8 r n b k q b n r");
+        assert_eq!(lines[8], "  h g f e d c b a");
+    }
+
+    #[test]
+    fn test_chess_forced_move_needs_no_search() {
+        // The king's only move is to take the queen beside it.
+        let mut chess = Chess::from_fen("k7/8/8/8/8/8/1q6/K7 w - - 0 1").unwrap();
+        assert_eq!(chess.legal_move_count(), 1);
+
+        let forced = chess.get_available_moves()[0].clone();
+        let (best_move, stats) = chess.get_best_move_with_stats(6, false);
+        assert_eq!(best_move, forced);
+        assert!(stats.nodes_visited <= 1);
+    }
+
+    #[test]
+    fn test_chess_undo_move_takes_back_the_last_move() {
+        let mut chess = Chess::default();
+        assert!(chess.undo_move().is_err());
+
+        play_san(&mut chess, "e4");
+        let after_e4 = chess.position_hash();
+        play_san(&mut chess, "d5");
+        play_san(&mut chess, "exd5");
+        assert!(chess.undo_move().is_ok());
+        assert!(chess.undo_move().is_ok());
+        assert_eq!(chess.position_hash(), after_e4);
+
+        assert!(chess.undo_move().is_ok());
+        assert_eq!(chess.board(), ChessGame::default().board());
+        assert!(chess.moves_played.is_empty());
+    }
+
+    #[test]
+    fn test_chess_material_is_kept_up_to_date() {
+        // White wins a pawn and then promotes with a capture.
+        let mut chess = Chess::from_fen("1r4k1/P7/8/3p4/4P3/8/8/6K1 w - - 0 1").unwrap();
+        assert_eq!(chess.evaluate(), -40.);
+
+        for san in ["exd5", "Kf7", "axb8=Q"] {
+            play_san(&mut chess, san);
+            chess.evaluate();
+            assert_eq!(
+                chess.material.get(),
+                Some(super::count_material(chess.board()))
+            );
+        }
+        assert_eq!(chess.evaluate(), 100.);
+
+        while chess.undo().is_ok() {
+            assert_eq!(
+                chess.material.get(),
+                Some(super::count_material(chess.board()))
+            );
+        }
+        assert_eq!(chess.evaluate(), -40.);
+    }
+
+    #[test]
+    fn test_chess_noisy_moves_are_captures_and_promotions() {
+        let chess = Chess::from_fen("6k1/8/4p3/3p4/8/8/P7/3Q2K1 w - - 0 1").unwrap();
+        let noisy = chess.get_noisy_moves();
+        assert_eq!(noisy, vec![find_move(&chess, Square::D1, Square::D5)]);
+        assert!(noisy.iter().flatten().all(|mv| mv.is_capture()));
+        assert!(!chess.is_quiet());
+
+        // Promoting is noisy with or without a capture.
+        let chess = Chess::from_fen("1r4k1/P7/8/8/8/8/8/6K1 w - - 0 1").unwrap();
+        let noisy = chess.get_noisy_moves();
+        assert_eq!(noisy.len(), 8);
+        assert!(noisy.iter().flatten().all(|mv| mv.is_promotion()));
+        assert_eq!(
+            noisy.iter().flatten().filter(|mv| mv.is_capture()).count(),
+            4
+        );
+
+        assert!(Chess::new().get_noisy_moves().is_empty());
+        assert!(Chess::new().is_quiet());
+    }
 }
@@ -0,0 +1,336 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use crate::strategy::game_strategy::GameStrategy;
+
+/// The number of discs in a row needed to win.
+const WIN_LENGTH: usize = 4;
+
+/// A game of Connect Four, where the players take turns
+/// dropping discs into the columns of an upright grid,
+/// trying to line up four of their own.
+///
+/// The board is stored row by row, starting from the
+/// bottom row, so a disc dropped into a column comes to
+/// rest at the lowest empty index of that column.
+#[derive(Debug, Clone)]
+pub struct ConnectFour {
+    pub board: Vec<char>,
+    pub width: usize,
+    pub height: usize,
+    pub default_char: char,
+    pub maximizer: char,
+    pub minimizer: char,
+}
+
+impl Display for ConnectFour {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in (0..self.height).rev() {
+            for col in 0..self.width {
+                write!(f, "{}", self.board[self.index(row, col)])?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for ConnectFour {
+    fn default() -> Self {
+        ConnectFour::new(7, 6)
+    }
+}
+
+impl ConnectFour {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            board: vec!['-'; width * height],
+            width,
+            height,
+            default_char: '-',
+            maximizer: 'o',
+            minimizer: 'x',
+        }
+    }
+
+    pub fn with_player_1(self, character: char) -> Self {
+        Self {
+            maximizer: character,
+            ..self
+        }
+    }
+    pub fn with_player_2(self, character: char) -> Self {
+        Self {
+            minimizer: character,
+            ..self
+        }
+    }
+    pub fn with_default_char(self, character: char) -> Self {
+        let board = self
+            .board
+            .iter()
+            .map(|&x| if x == self.default_char { character } else { x })
+            .collect();
+        Self {
+            board,
+            default_char: character,
+            ..self
+        }
+    }
+
+    /// The flat index of a cell, counting rows from the bottom.
+    fn index(&self, row: usize, col: usize) -> usize {
+        row * self.width + col
+    }
+
+    /// The number of discs already dropped into a column.
+    fn column_height(&self, col: usize) -> usize {
+        (0..self.height)
+            .take_while(|&row| self.board[self.index(row, col)] != self.default_char)
+            .count()
+    }
+
+    /// Every run of `WIN_LENGTH` cells on the board,
+    /// horizontally, vertically, and along both diagonals.
+    fn windows(&self) -> Vec<[usize; WIN_LENGTH]> {
+        let mut windows = vec![];
+        let directions: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+
+        for row in 0..self.height as isize {
+            for col in 0..self.width as isize {
+                for &(d_row, d_col) in directions.iter() {
+                    let end_row = row + d_row * (WIN_LENGTH as isize - 1);
+                    let end_col = col + d_col * (WIN_LENGTH as isize - 1);
+                    if end_row < 0
+                        || end_row >= self.height as isize
+                        || end_col < 0
+                        || end_col >= self.width as isize
+                    {
+                        continue;
+                    }
+                    let mut window = [0; WIN_LENGTH];
+                    for (step, cell) in window.iter_mut().enumerate() {
+                        let r = (row + d_row * step as isize) as usize;
+                        let c = (col + d_col * step as isize) as usize;
+                        *cell = self.index(r, c);
+                    }
+                    windows.push(window);
+                }
+            }
+        }
+        windows
+    }
+
+    /// Score a run of cells by how close
+    /// one player is to completing it.
+    fn score_window(&self, window: &[usize; WIN_LENGTH]) -> f64 {
+        let count = |ch: char| window.iter().filter(|&&idx| self.board[idx] == ch).count();
+        let (max_count, min_count) = (count(self.maximizer), count(self.minimizer));
+
+        let threat = |n: usize| match n {
+            3 => 5.,
+            2 => 2.,
+            _ => 0.,
+        };
+
+        if min_count == 0 {
+            threat(max_count)
+        } else if max_count == 0 {
+            -threat(min_count)
+        } else {
+            0.
+        }
+    }
+}
+
+/// Endow upon ConnectFour the ability to
+/// play games.
+impl GameStrategy for ConnectFour {
+    /// The Player is a char.
+    /// Usually one of 'o', 'O', 'x', 'X', '-'.
+    type Player = char;
+
+    /// The Move is the column to drop a disc into,
+    /// i.e. in range `[0, width - 1]`.
+    type Move = usize;
+
+    /// The Board is a single vector of length `width * height`.
+    type Board = Vec<char>;
+
+    /// A decisive game scores `WIN_SCORE` or `LOSS_SCORE`, otherwise
+    /// every open run of four scores by how many of
+    /// its cells one player already holds.
+    fn evaluate(&self) -> f64 {
+        match self.get_winner() {
+            Some(winner) if winner == self.maximizer => Self::WIN_SCORE,
+            Some(_) => Self::LOSS_SCORE,
+            None => self
+                .windows()
+                .iter()
+                .map(|window| self.score_window(window))
+                .sum(),
+        }
+    }
+
+    fn get_winner(&self) -> Option<Self::Player> {
+        self.windows().iter().find_map(|window| {
+            let first = self.board[window[0]];
+            if first != self.default_char && window.iter().all(|&idx| self.board[idx] == first) {
+                Some(first)
+            } else {
+                None
+            }
+        })
+    }
+
+    fn is_game_tied(&self) -> bool {
+        self.get_winner().is_none() && self.legal_move_count() == 0
+    }
+
+    fn is_game_complete(&self) -> bool {
+        self.get_winner().is_some() || self.legal_move_count() == 0
+    }
+
+    fn get_available_moves(&self) -> Vec<Self::Move> {
+        (0..self.width)
+            .filter(|&col| self.column_height(col) < self.height)
+            .collect()
+    }
+
+    fn play(&mut self, &mv: &Self::Move, maximizer: bool) {
+        // player: true means the maximizer's turn.
+        let idx = self.index(self.column_height(mv), mv);
+
+        if maximizer {
+            self.board[idx] = self.maximizer;
+        } else {
+            self.board[idx] = self.minimizer;
+        }
+    }
+
+    fn clear(&mut self, &mv: &Self::Move) {
+        let height = self.column_height(mv);
+        if height > 0 {
+            let idx = self.index(height - 1, mv);
+            self.board[idx] = self.default_char;
+        }
+    }
+
+    fn reset(&mut self) {
+        self.board.fill(self.default_char);
+    }
+
+    /// The maximizer moves first, so it is to move
+    /// whenever an even number of cells are filled.
+    fn current_player(&self) -> bool {
+        let filled = self
+            .board
+            .iter()
+            .filter(|&&ch| ch != self.default_char)
+            .count();
+        filled % 2 == 0
+    }
+
+    fn get_board(&self) -> &Self::Board {
+        &self.board
+    }
+
+    fn board_dimensions(&self) -> Option<(usize, usize)> {
+        Some((self.height, self.width))
+    }
+
+    fn is_a_valid_move(&self, &mv: &Self::Move) -> bool {
+        mv < self.width && self.column_height(mv) < self.height
+    }
+
+    fn get_a_sentinel_move(&self) -> Self::Move {
+        self.width
+    }
+
+    fn position_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.board.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Try the central columns first,
+    /// since those take part in the most runs.
+    fn order_moves(&self, mut moves: Vec<Self::Move>, _is_maximizing: bool) -> Vec<Self::Move> {
+        let center = self.width / 2;
+        moves.sort_by_key(|&col| col.abs_diff(center));
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+
+    #[test]
+    fn vertical_win() {
+        let mut game = ConnectFour::default();
+        for _ in 0..4 {
+            game.play(&3, true);
+        }
+        assert_eq!(game.get_winner(), Some('o'));
+        assert!(game.is_game_complete());
+        assert_eq!(game.evaluate(), 1000.);
+    }
+
+    #[test]
+    fn horizontal_win() {
+        let mut game = ConnectFour::default();
+        for col in 2..6 {
+            game.play(&col, false);
+        }
+        assert_eq!(game.get_winner(), Some('x'));
+        assert_eq!(game.evaluate(), -1000.);
+    }
+
+    #[test]
+    fn diagonal_win() {
+        let mut game = ConnectFour::default();
+        for col in 0..4 {
+            for _ in 0..col {
+                game.play(&col, false);
+            }
+            game.play(&col, true);
+        }
+        assert_eq!(game.get_winner(), Some('o'));
+    }
+
+    #[test]
+    fn full_column_is_not_available() {
+        let mut game = ConnectFour::default();
+        for idx in 0..game.height {
+            assert!(game.is_a_valid_move(&0));
+            game.play(&0, idx % 2 == 0);
+        }
+        assert!(!game.is_a_valid_move(&0));
+        assert_eq!(game.get_available_moves(), vec![1, 2, 3, 4, 5, 6]);
+        assert_eq!(game.get_winner(), None);
+    }
+
+    #[test]
+    fn clear_removes_the_top_disc() {
+        let mut game = ConnectFour::default();
+        game.play(&2, true);
+        game.play(&2, false);
+        game.clear(&2);
+        assert_eq!(game.board[2], 'o');
+        assert_eq!(game.board[game.width + 2], '-');
+    }
+
+    #[test]
+    fn engine_completes_a_winning_row() {
+        let mut game = ConnectFour::default();
+        for col in 1..4 {
+            game.play(&col, true);
+            game.play(&col, false);
+        }
+        let best_move = game.get_best_move(4, false);
+        assert!(best_move == 0 || best_move == 4);
+    }
+}
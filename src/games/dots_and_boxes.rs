@@ -0,0 +1,359 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use crate::strategy::game_strategy::GameStrategy;
+
+/// A game of Dots and Boxes on a grid of dots, where the
+/// players take turns drawing an edge between two adjacent
+/// dots. Whoever draws the fourth side of a box claims it
+/// and must move again. Whoever claims more boxes wins.
+///
+/// Edges are numbered with the horizontal ones first, row
+/// by row from the top, followed by the vertical ones.
+#[derive(Debug, Clone)]
+pub struct DotsAndBoxes {
+    /// The number of rows of dots.
+    pub rows: usize,
+    /// The number of columns of dots.
+    pub cols: usize,
+    /// Whether each edge has been drawn.
+    pub edges: Vec<bool>,
+    /// The owner of each box, row by row, where `true`
+    /// is the maximizer, once all its sides are drawn.
+    pub boxes: Vec<Option<bool>>,
+    /// The edges drawn so far, along with
+    /// the boxes each of them completed.
+    history: Vec<(usize, Vec<usize>)>,
+}
+
+impl Display for DotsAndBoxes {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in 0..self.rows {
+            let mut line = String::from("+");
+            for col in 0..self.cols - 1 {
+                let drawn = self.edges[self.horizontal(row, col)];
+                line.push_str(if drawn { "---+" } else { "   +" });
+            }
+            writeln!(f, "{}", line)?;
+
+            if row + 1 == self.rows {
+                break;
+            }
+            let mut line = String::new();
+            for col in 0..self.cols {
+                line.push(if self.edges[self.vertical(row, col)] {
+                    '|'
+                } else {
+                    ' '
+                });
+                if col + 1 < self.cols {
+                    line.push_str(match self.boxes[row * (self.cols - 1) + col] {
+                        Some(true) => " M ",
+                        Some(false) => " m ",
+                        None => "   ",
+                    });
+                }
+            }
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+impl DotsAndBoxes {
+    /// A game on a grid of `rows` by `cols` dots.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        assert!(rows >= 2 && cols >= 2, "Need at least a 2x2 grid of dots.");
+        let num_edges = rows * (cols - 1) + (rows - 1) * cols;
+        Self {
+            rows,
+            cols,
+            edges: vec![false; num_edges],
+            boxes: vec![None; (rows - 1) * (cols - 1)],
+            history: vec![],
+        }
+    }
+
+    /// The index of the edge from the dot at
+    /// (`row`, `col`) to the one on its right.
+    pub fn horizontal(&self, row: usize, col: usize) -> usize {
+        row * (self.cols - 1) + col
+    }
+
+    /// The index of the edge from the dot at
+    /// (`row`, `col`) to the one below it.
+    pub fn vertical(&self, row: usize, col: usize) -> usize {
+        self.rows * (self.cols - 1) + row * self.cols + col
+    }
+
+    /// The number of boxes claimed by the maximizer
+    /// and by the minimizer, in that order.
+    pub fn box_counts(&self) -> (usize, usize) {
+        let maximizer = self.boxes.iter().filter(|&&b| b == Some(true)).count();
+        let minimizer = self.boxes.iter().filter(|&&b| b == Some(false)).count();
+        (maximizer, minimizer)
+    }
+
+    /// The four edges around the box whose
+    /// top-left corner is the dot at (`row`, `col`).
+    fn sides(&self, row: usize, col: usize) -> [usize; 4] {
+        [
+            self.horizontal(row, col),
+            self.horizontal(row + 1, col),
+            self.vertical(row, col),
+            self.vertical(row, col + 1),
+        ]
+    }
+
+    /// The boxes, as (row, col) of their top-left
+    /// corners, that a given edge is a side of.
+    fn boxes_beside(&self, edge: usize) -> Vec<(usize, usize)> {
+        let num_horizontal = self.rows * (self.cols - 1);
+        let mut beside = vec![];
+
+        if edge < num_horizontal {
+            let (row, col) = (edge / (self.cols - 1), edge % (self.cols - 1));
+            if row > 0 {
+                beside.push((row - 1, col));
+            }
+            if row + 1 < self.rows {
+                beside.push((row, col));
+            }
+        } else {
+            let (row, col) = (
+                (edge - num_horizontal) / self.cols,
+                (edge - num_horizontal) % self.cols,
+            );
+            if col > 0 {
+                beside.push((row, col - 1));
+            }
+            if col + 1 < self.cols {
+                beside.push((row, col));
+            }
+        }
+        beside
+    }
+
+    /// The number of sides of a box already drawn.
+    fn sides_drawn(&self, row: usize, col: usize) -> usize {
+        self.sides(row, col)
+            .iter()
+            .filter(|&&edge| self.edges[edge])
+            .count()
+    }
+
+    /// How promising an edge is as a move: completing
+    /// a box first, then edges that don't hand the
+    /// opponent a box, then everything else.
+    fn move_priority(&self, edge: usize) -> usize {
+        let most_drawn = self
+            .boxes_beside(edge)
+            .iter()
+            .map(|&(row, col)| self.sides_drawn(row, col))
+            .max()
+            .unwrap_or(0);
+        match most_drawn {
+            3 => 0,
+            2 => 2,
+            _ => 1,
+        }
+    }
+}
+
+/// Endow upon DotsAndBoxes the ability to
+/// play games.
+impl GameStrategy for DotsAndBoxes {
+    /// The Player is true for the maximizer
+    /// and false for the minimizer.
+    type Player = bool;
+
+    /// The Move is the index of the edge to draw.
+    type Move = usize;
+
+    /// The Board is whether each edge has been drawn.
+    type Board = Vec<bool>;
+
+    /// The difference in boxes claimed while the game is on,
+    /// and `WIN_SCORE` or `LOSS_SCORE` once it is over.
+    fn evaluate(&self) -> f64 {
+        if self.is_game_complete() {
+            return match self.get_winner() {
+                Some(true) => Self::WIN_SCORE,
+                Some(false) => Self::LOSS_SCORE,
+                None => 0.,
+            };
+        }
+        let (maximizer, minimizer) = self.box_counts();
+        maximizer as f64 - minimizer as f64
+    }
+
+    /// Whoever claimed more boxes, once every edge is drawn.
+    fn get_winner(&self) -> Option<Self::Player> {
+        if !self.is_game_complete() {
+            return None;
+        }
+        let (maximizer, minimizer) = self.box_counts();
+        match maximizer.cmp(&minimizer) {
+            std::cmp::Ordering::Greater => Some(true),
+            std::cmp::Ordering::Less => Some(false),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    fn is_game_tied(&self) -> bool {
+        let (maximizer, minimizer) = self.box_counts();
+        self.is_game_complete() && maximizer == minimizer
+    }
+
+    fn is_game_complete(&self) -> bool {
+        self.edges.iter().all(|&drawn| drawn)
+    }
+
+    fn get_available_moves(&self) -> Vec<Self::Move> {
+        (0..self.edges.len())
+            .filter(|&edge| !self.edges[edge])
+            .collect()
+    }
+
+    fn play(&mut self, &mv: &Self::Move, maximizer: bool) {
+        self.edges[mv] = true;
+
+        let mut completed = vec![];
+        for (row, col) in self.boxes_beside(mv) {
+            if self.sides_drawn(row, col) == 4 {
+                let idx = row * (self.cols - 1) + col;
+                self.boxes[idx] = Some(maximizer);
+                completed.push(idx);
+            }
+        }
+        self.history.push((mv, completed));
+    }
+
+    fn clear(&mut self, &mv: &Self::Move) {
+        self.edges[mv] = false;
+        if let Some(pos) = self.history.iter().rposition(|&(edge, _)| edge == mv) {
+            let (_, completed) = self.history.remove(pos);
+            for idx in completed {
+                self.boxes[idx] = None;
+            }
+        }
+    }
+
+    fn reset(&mut self) {
+        self.edges.fill(false);
+        self.boxes.fill(None);
+        self.history.clear();
+    }
+
+    /// The turn passes with every edge that completes no box.
+    fn current_player(&self) -> bool {
+        let passes = self
+            .history
+            .iter()
+            .filter(|(_, completed)| completed.is_empty())
+            .count();
+        passes % 2 == 0
+    }
+
+    fn get_board(&self) -> &Self::Board {
+        &self.edges
+    }
+
+    fn is_a_valid_move(&self, &mv: &Self::Move) -> bool {
+        mv < self.edges.len() && !self.edges[mv]
+    }
+
+    fn get_a_sentinel_move(&self) -> Self::Move {
+        self.edges.len()
+    }
+
+    fn position_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.edges.hash(&mut hasher);
+        self.boxes.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Completing a box earns another move.
+    fn move_passes_turn(&self, &mv: &Self::Move) -> bool {
+        self.boxes_beside(mv)
+            .iter()
+            .all(|&(row, col)| self.sides_drawn(row, col) != 3)
+    }
+
+    fn order_moves(&self, mut moves: Vec<Self::Move>, _is_maximizing: bool) -> Vec<Self::Move> {
+        moves.sort_by_key(|&mv| self.move_priority(mv));
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+
+    #[test]
+    fn completing_a_box_grants_another_move() {
+        let mut game = DotsAndBoxes::new(2, 3);
+        let [top, bottom, left, right] = game.sides(0, 0);
+
+        for edge in [top, bottom, left] {
+            assert!(game.move_passes_turn(&edge));
+            game.play(&edge, true);
+        }
+        assert!(!game.move_passes_turn(&right));
+
+        game.play(&right, false);
+        assert_eq!(game.boxes, vec![Some(false), None]);
+        assert_eq!(game.evaluate(), -1.);
+
+        game.clear(&right);
+        assert_eq!(game.boxes, vec![None, None]);
+    }
+
+    #[test]
+    fn final_score_decides_the_winner() {
+        let mut game = DotsAndBoxes::new(2, 3);
+        for edge in game.sides(0, 0) {
+            game.play(&edge, true);
+        }
+        assert!(!game.is_game_complete());
+
+        // The shared side is already drawn.
+        for edge in game.sides(0, 1) {
+            if game.is_a_valid_move(&edge) {
+                game.play(&edge, false);
+            }
+        }
+        assert!(game.is_game_complete());
+        assert!(game.is_game_tied());
+        assert_eq!(game.get_winner(), None);
+
+        game.boxes[1] = Some(true);
+        assert_eq!(game.get_winner(), Some(true));
+        assert_eq!(game.evaluate(), DotsAndBoxes::WIN_SCORE);
+    }
+
+    #[test]
+    fn engine_takes_the_box_and_moves_again() {
+        // The three sides of the left box not shared with the
+        // right one are drawn, and two of the right box's are.
+        let mut game = DotsAndBoxes::new(2, 3);
+        let [top, bottom, left, shared] = game.sides(0, 0);
+        let [right_top, _, _, right] = game.sides(0, 1);
+        for edge in [top, bottom, left, right_top, right] {
+            game.play(&edge, true);
+        }
+
+        // Drawing the shared side takes the left box and leaves
+        // the right one a side short, for the same player to take.
+        let variation = game.get_principal_variation(4, false);
+        assert_eq!(variation[0], shared);
+        assert_eq!(variation.len(), 2);
+
+        game.play(&variation[0], true);
+        game.play(&variation[1], true);
+        assert_eq!(game.get_winner(), Some(true));
+    }
+}
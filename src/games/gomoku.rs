@@ -0,0 +1,326 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use crate::strategy::game_strategy::GameStrategy;
+
+/// The number of stones in a row needed to win.
+const WIN_LENGTH: usize = 5;
+
+/// The four directions a line of stones can run in.
+const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+
+/// A game of free-style Gomoku, where the first player
+/// to line up five or more stones in a row wins.
+///
+/// Since a new win can only pass through the stone just
+/// played, only the lines through the last move are checked,
+/// so detecting the end of the game doesn't depend on the
+/// size of the board.
+#[derive(Debug, Clone)]
+pub struct Gomoku {
+    pub board: Vec<char>,
+    pub size: usize,
+    pub default_char: char,
+    pub maximizer: char,
+    pub minimizer: char,
+    /// The moves played so far, in order.
+    history: Vec<usize>,
+}
+
+impl Display for Gomoku {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.board.chunks(self.size) {
+            for &x in row.iter() {
+                write!(f, "{}", x)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Gomoku {
+    fn default() -> Self {
+        Gomoku::new(15)
+    }
+}
+
+impl Gomoku {
+    pub fn new(size: usize) -> Self {
+        Self {
+            board: vec!['-'; size * size],
+            size,
+            default_char: '-',
+            maximizer: 'o',
+            minimizer: 'x',
+            history: vec![],
+        }
+    }
+
+    /// The cell a number of steps away in a direction, if on the board.
+    fn step(&self, idx: usize, (d_row, d_col): (isize, isize), steps: isize) -> Option<usize> {
+        let row = (idx / self.size) as isize + d_row * steps;
+        let col = (idx % self.size) as isize + d_col * steps;
+        let size = self.size as isize;
+        if (0..size).contains(&row) && (0..size).contains(&col) {
+            Some(row as usize * size as usize + col as usize)
+        } else {
+            None
+        }
+    }
+
+    /// The number of stones in the run through `idx` along a
+    /// direction, and how many of the run's two ends are open.
+    fn run_through(&self, idx: usize, direction: (isize, isize)) -> (usize, usize) {
+        let ch = self.board[idx];
+        let mut length = 1;
+        let mut open_ends = 0;
+
+        for &sign in [-1, 1].iter() {
+            let mut steps = 1;
+            loop {
+                match self.step(idx, direction, sign * steps) {
+                    Some(cell) if self.board[cell] == ch => {
+                        length += 1;
+                        steps += 1;
+                    }
+                    Some(cell) if self.board[cell] == self.default_char => {
+                        open_ends += 1;
+                        break;
+                    }
+                    _ => break,
+                }
+            }
+        }
+        (length, open_ends)
+    }
+
+    /// The player with five or more in a row
+    /// through the given cell, if any.
+    pub fn winner_through(&self, idx: usize) -> Option<char> {
+        let ch = self.board[idx];
+        if ch == self.default_char {
+            return None;
+        }
+        if DIRECTIONS
+            .iter()
+            .any(|&direction| self.run_through(idx, direction).0 >= WIN_LENGTH)
+        {
+            Some(ch)
+        } else {
+            None
+        }
+    }
+
+    /// Score a run of stones by its length
+    /// and how many of its ends are open.
+    fn score_run(length: usize, open_ends: usize) -> f64 {
+        match (length, open_ends) {
+            (4, 2) => 100.,
+            (4, 1) => 20.,
+            (3, 2) => 10.,
+            (3, 1) => 2.,
+            (2, 2) => 2.,
+            (2, 1) => 0.5,
+            _ => 0.,
+        }
+    }
+}
+
+/// Endow upon Gomoku the ability to
+/// play games.
+impl GameStrategy for Gomoku {
+    /// The Player is a char.
+    /// Usually one of 'o', 'O', 'x', 'X', '-'.
+    type Player = char;
+
+    /// The Move is a single number representing an
+    /// index of the Board vector, i.e. in range
+    /// `[0, (size * size) - 1]`.
+    type Move = usize;
+
+    /// The Board is a single vector of length `size * size`.
+    type Board = Vec<char>;
+
+    /// A decisive game scores `WIN_SCORE` or `LOSS_SCORE`, otherwise
+    /// every run of two to four stones scores by
+    /// its length and how many of its ends are open.
+    fn evaluate(&self) -> f64 {
+        match self.get_winner() {
+            Some(winner) if winner == self.maximizer => return Self::WIN_SCORE,
+            Some(_) => return Self::LOSS_SCORE,
+            None => {}
+        }
+
+        let mut score = 0.;
+        for (idx, &ch) in self.board.iter().enumerate() {
+            if ch == self.default_char {
+                continue;
+            }
+            for &direction in DIRECTIONS.iter() {
+                // Only count a run from its first stone.
+                if let Some(prev) = self.step(idx, direction, -1) {
+                    if self.board[prev] == ch {
+                        continue;
+                    }
+                }
+                let (length, open_ends) = self.run_through(idx, direction);
+                let value = Self::score_run(length, open_ends);
+                if ch == self.maximizer {
+                    score += value;
+                } else {
+                    score -= value;
+                }
+            }
+        }
+        score
+    }
+
+    fn get_winner(&self) -> Option<Self::Player> {
+        self.history
+            .last()
+            .and_then(|&last| self.winner_through(last))
+    }
+
+    fn is_game_tied(&self) -> bool {
+        self.get_winner().is_none() && self.history.len() == self.board.len()
+    }
+
+    fn is_game_complete(&self) -> bool {
+        self.history.len() == self.board.len() || self.get_winner().is_some()
+    }
+
+    fn get_available_moves(&self) -> Vec<Self::Move> {
+        (0..self.board.len())
+            .filter(|&idx| self.board[idx] == self.default_char)
+            .collect()
+    }
+
+    fn play(&mut self, &mv: &Self::Move, maximizer: bool) {
+        // player: true means the maximizer's turn.
+        if maximizer {
+            self.board[mv] = self.maximizer;
+        } else {
+            self.board[mv] = self.minimizer;
+        }
+        self.history.push(mv);
+    }
+
+    fn clear(&mut self, &mv: &Self::Move) {
+        self.board[mv] = self.default_char;
+        if let Some(pos) = self.history.iter().rposition(|&idx| idx == mv) {
+            self.history.remove(pos);
+        }
+    }
+
+    fn reset(&mut self) {
+        self.board.fill(self.default_char);
+        self.history.clear();
+    }
+
+    /// The maximizer moves first, so it is to move
+    /// whenever an even number of cells are filled.
+    fn current_player(&self) -> bool {
+        let filled = self
+            .board
+            .iter()
+            .filter(|&&ch| ch != self.default_char)
+            .count();
+        filled % 2 == 0
+    }
+
+    fn get_board(&self) -> &Self::Board {
+        &self.board
+    }
+
+    fn board_dimensions(&self) -> Option<(usize, usize)> {
+        Some((self.size, self.size))
+    }
+
+    fn is_a_valid_move(&self, &mv: &Self::Move) -> bool {
+        mv < self.board.len() && self.board[mv] == self.default_char
+    }
+
+    fn get_a_sentinel_move(&self) -> Self::Move {
+        self.board.len() + 1
+    }
+
+    fn position_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.board.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Try the cells closest to the last move first,
+    /// since that's where the fighting is.
+    fn order_moves(&self, mut moves: Vec<Self::Move>, _is_maximizing: bool) -> Vec<Self::Move> {
+        let center = self.size * self.size / 2;
+        let focus = self.history.last().copied().unwrap_or(center);
+        let (row, col) = (focus / self.size, focus % self.size);
+
+        moves.sort_by_key(|&mv| {
+            let (r, c) = (mv / self.size, mv % self.size);
+            r.abs_diff(row).max(c.abs_diff(col))
+        });
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn five_in_a_row_wins() {
+        let mut game = Gomoku::default();
+        for col in 3..7 {
+            game.play(&(7 * 15 + col), true);
+        }
+        assert_eq!(game.get_winner(), None);
+
+        game.play(&(7 * 15 + 7), true);
+        assert_eq!(game.get_winner(), Some('o'));
+        assert!(game.is_game_complete());
+        assert_eq!(game.evaluate(), 1000.);
+    }
+
+    #[test]
+    fn blocked_four_is_not_a_win() {
+        let mut game = Gomoku::default();
+        game.play(&(7 * 15 + 2), false);
+        for col in 3..7 {
+            game.play(&(7 * 15 + col), true);
+        }
+        game.play(&(7 * 15 + 7), false);
+
+        assert_eq!(game.get_winner(), None);
+        assert!(!game.is_game_complete());
+    }
+
+    #[test]
+    fn open_runs_outscore_blocked_runs() {
+        let mut open = Gomoku::default();
+        for col in 5..8 {
+            open.play(&(7 * 15 + col), true);
+        }
+
+        let mut blocked = open.clone();
+        blocked.play(&(7 * 15 + 4), false);
+
+        assert!(open.evaluate() > 0.);
+        assert!(open.evaluate() > blocked.evaluate());
+    }
+
+    #[test]
+    fn clear_restores_the_previous_last_move() {
+        let mut game = Gomoku::new(9);
+        for col in 0..5 {
+            game.play(&col, true);
+        }
+        assert_eq!(game.get_winner(), Some('o'));
+
+        game.clear(&4);
+        assert_eq!(game.get_winner(), None);
+    }
+}
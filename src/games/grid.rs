@@ -0,0 +1,207 @@
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::ops::{Deref, DerefMut};
+
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
+/// The directions a line can run across a grid: along a row,
+/// down a column, and down either diagonal.
+pub const DIRECTIONS: [(isize, isize); 4] = [(0, 1), (1, 0), (1, 1), (1, -1)];
+
+/// A rectangular grid of cells, stored row by row, that
+/// turns between flat indices and rows and columns.
+/// It derefs to the slice of its cells, so cells can
+/// also be reached by their flat index.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    rows: usize,
+    cols: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// A grid of the given rows and columns with every cell set to `fill`.
+    pub fn new(rows: usize, cols: usize, fill: T) -> Self {
+        Self {
+            rows,
+            cols,
+            cells: alloc::vec![fill; rows * cols],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// A grid of the given rows and columns holding
+    /// the given cells, row by row, or `None` if
+    /// there are too few or too many of them.
+    pub fn from_cells(rows: usize, cols: usize, cells: Vec<T>) -> Option<Self> {
+        (cells.len() == rows * cols).then_some(Self { rows, cols, cells })
+    }
+
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// The flat index of the cell at a given row and column.
+    pub fn to_index(&self, row: usize, col: usize) -> usize {
+        row * self.cols + col
+    }
+
+    /// The row of the cell at a given flat index.
+    pub fn row(&self, idx: usize) -> usize {
+        idx / self.cols
+    }
+
+    /// The column of the cell at a given flat index.
+    pub fn col(&self, idx: usize) -> usize {
+        idx % self.cols
+    }
+
+    /// Whether a row and column that may lie
+    /// off the grid on either side lie on it.
+    pub fn in_bounds(&self, row: isize, col: isize) -> bool {
+        (0..self.rows as isize).contains(&row) && (0..self.cols as isize).contains(&col)
+    }
+
+    /// The cell at a given row and column,
+    /// or `None` if it is off the grid.
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row < self.rows && col < self.cols {
+            Some(&self.cells[self.to_index(row, col)])
+        } else {
+            None
+        }
+    }
+
+    /// The cell at a row and column that may
+    /// lie off the grid on either side.
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        self.get(usize::try_from(row).ok()?, usize::try_from(col).ok()?)
+    }
+
+    /// Put a value in the cell at a given row and column,
+    /// returning the one it held, or `None` if it is off
+    /// the grid, when nothing changes.
+    pub fn set(&mut self, row: usize, col: usize, value: T) -> Option<T> {
+        if row < self.rows && col < self.cols {
+            let idx = self.to_index(row, col);
+            Some(core::mem::replace(&mut self.cells[idx], value))
+        } else {
+            None
+        }
+    }
+
+    /// The flat indices of the cells from a given row and
+    /// column onward in a given direction, up to the edge.
+    pub fn line(
+        &self,
+        row: usize,
+        col: usize,
+        d_row: isize,
+        d_col: isize,
+    ) -> impl Iterator<Item = usize> + '_ {
+        let (row, col) = (row as isize, col as isize);
+        (0..)
+            .map(move |k| (row + d_row * k, col + d_col * k))
+            .take_while(move |&(r, c)| self.in_bounds(r, c))
+            .map(move |(r, c)| self.to_index(r as usize, c as usize))
+    }
+
+    /// The flat indices of every run of `len` cells in a line,
+    /// found from each cell in turn, row by row, along its
+    /// row, its column, and then the two diagonals.
+    pub fn runs(&self, len: usize) -> impl Iterator<Item = Vec<usize>> + '_ {
+        let cells = (0..self.rows).flat_map(move |row| (0..self.cols).map(move |col| (row, col)));
+        cells
+            .flat_map(|cell| DIRECTIONS.iter().map(move |&direction| (cell, direction)))
+            .filter_map(move |((row, col), (d_row, d_col))| {
+                let run: Vec<usize> = self.line(row, col, d_row, d_col).take(len).collect();
+                (len > 0 && run.len() == len).then_some(run)
+            })
+    }
+}
+
+impl<T> Deref for Grid<T> {
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target {
+        &self.cells
+    }
+}
+
+impl<T> DerefMut for Grid<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.cells
+    }
+}
+
+impl<T: PartialEq> PartialEq<Vec<T>> for Grid<T> {
+    fn eq(&self, other: &Vec<T>) -> bool {
+        &self.cells == other
+    }
+}
+
+/// A grid is written as its cells alone, row by row.
+#[cfg(feature = "serde")]
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.cells.serialize(serializer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn cells_are_found_by_row_and_column() {
+        let mut grid = Grid::from_cells(2, 3, vec![0, 1, 2, 3, 4, 5]).unwrap();
+        assert_eq!((grid.rows(), grid.cols()), (2, 3));
+        assert_eq!(grid.to_index(1, 2), 5);
+        assert_eq!((grid.row(4), grid.col(4)), (1, 1));
+        assert_eq!(grid.get(1, 0), Some(&3));
+        assert_eq!(grid.get(2, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+        assert_eq!(grid.get_signed(-1, 0), None);
+        assert!(grid.in_bounds(1, 2));
+        assert!(!grid.in_bounds(0, -1));
+
+        assert_eq!(grid.set(0, 1, 9), Some(1));
+        assert_eq!(grid.set(5, 5, 9), None);
+        assert_eq!(grid[1], 9);
+        assert_eq!(grid, vec![0, 9, 2, 3, 4, 5]);
+
+        assert!(Grid::from_cells(2, 2, vec![0; 3]).is_none());
+    }
+
+    #[test]
+    fn lines_run_to_the_edge() {
+        let grid = Grid::new(3, 4, '-');
+        assert_eq!(grid.line(0, 1, 0, 1).collect::<Vec<_>>(), [1, 2, 3]);
+        assert_eq!(grid.line(0, 3, 1, 0).collect::<Vec<_>>(), [3, 7, 11]);
+        assert_eq!(grid.line(0, 0, 1, 1).collect::<Vec<_>>(), [0, 5, 10]);
+        assert_eq!(grid.line(0, 3, 1, -1).collect::<Vec<_>>(), [3, 6, 9]);
+        assert_eq!(grid.line(2, 0, -1, 0).collect::<Vec<_>>(), [8, 4, 0]);
+    }
+
+    #[test]
+    fn runs_cover_every_line_of_a_length() {
+        let grid = Grid::new(3, 3, '-');
+        let runs: Vec<Vec<usize>> = grid.runs(3).collect();
+        assert_eq!(runs.len(), 8);
+        assert_eq!(runs[0], [0, 1, 2]);
+        assert!(runs.contains(&vec![2, 4, 6]));
+        assert!(runs.contains(&vec![1, 4, 7]));
+
+        // Three along the rows, four down the columns,
+        // and two down each diagonal.
+        assert_eq!(Grid::new(3, 2, 0).runs(2).count(), 3 + 4 + 2 + 2);
+        assert_eq!(grid.runs(4).count(), 0);
+    }
+}
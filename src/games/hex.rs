@@ -0,0 +1,345 @@
+use std::collections::hash_map::DefaultHasher;
+use std::collections::VecDeque;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use crate::strategy::game_strategy::GameStrategy;
+
+/// The six directions from a cell to its neighbours
+/// on a rhombus of hexagons.
+const NEIGHBOURS: [(isize, isize); 6] = [(-1, 0), (-1, 1), (0, -1), (0, 1), (1, -1), (1, 0)];
+
+/// A game of Hex on an n×n rhombus of hexagonal cells.
+/// The maximizer wins by connecting the top and bottom
+/// edges with a chain of stones, and the minimizer by
+/// connecting the left and right edges.
+///
+/// Exactly one of the two connections exists once the
+/// board is full, so the game can never be tied.
+#[derive(Debug, Clone)]
+pub struct Hex {
+    pub board: Vec<char>,
+    pub size: usize,
+    pub default_char: char,
+    pub maximizer: char,
+    pub minimizer: char,
+}
+
+impl Display for Hex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (row, cells) in self.board.chunks(self.size).enumerate() {
+            write!(f, "{}", " ".repeat(row))?;
+            for &x in cells.iter() {
+                write!(f, "{} ", x)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Hex {
+    fn default() -> Self {
+        Hex::new(11)
+    }
+}
+
+impl Hex {
+    pub fn new(size: usize) -> Self {
+        Self {
+            board: vec!['-'; size * size],
+            size,
+            default_char: '-',
+            maximizer: 'o',
+            minimizer: 'x',
+        }
+    }
+
+    /// The cells next to a given cell.
+    fn neighbours(&self, idx: usize) -> impl Iterator<Item = usize> + '_ {
+        let size = self.size as isize;
+        let (row, col) = ((idx / self.size) as isize, (idx % self.size) as isize);
+        NEIGHBOURS.iter().filter_map(move |&(d_row, d_col)| {
+            let (row, col) = (row + d_row, col + d_col);
+            if (0..size).contains(&row) && (0..size).contains(&col) {
+                Some((row * size + col) as usize)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Whether a cell lies on the edge a player starts from
+    /// (top for the maximizer, left for the minimizer), or
+    /// on the one they must reach (bottom or right).
+    fn on_edge(&self, idx: usize, maximizer: bool, far: bool) -> bool {
+        let (row, col) = (idx / self.size, idx % self.size);
+        let coord = if maximizer { row } else { col };
+        if far {
+            coord + 1 == self.size
+        } else {
+            coord == 0
+        }
+    }
+
+    /// Whether a player's stones connect their two edges,
+    /// found by flooding out from their starting edge.
+    fn connects(&self, maximizer: bool) -> bool {
+        let ch = if maximizer {
+            self.maximizer
+        } else {
+            self.minimizer
+        };
+        let mut seen = vec![false; self.board.len()];
+        let mut frontier: Vec<usize> = (0..self.board.len())
+            .filter(|&idx| self.on_edge(idx, maximizer, false) && self.board[idx] == ch)
+            .collect();
+        for &idx in &frontier {
+            seen[idx] = true;
+        }
+
+        while let Some(idx) = frontier.pop() {
+            if self.on_edge(idx, maximizer, true) {
+                return true;
+            }
+            for next in self.neighbours(idx) {
+                if !seen[next] && self.board[next] == ch {
+                    seen[next] = true;
+                    frontier.push(next);
+                }
+            }
+        }
+        false
+    }
+
+    /// The fewest stones a player still needs to place
+    /// to connect their edges, or `None` if they're cut off.
+    fn distance_to_connect(&self, maximizer: bool) -> Option<usize> {
+        let (own, other) = if maximizer {
+            (self.maximizer, self.minimizer)
+        } else {
+            (self.minimizer, self.maximizer)
+        };
+        let cost = |idx: usize| usize::from(self.board[idx] != own);
+        let mut distance = vec![usize::MAX; self.board.len()];
+        let mut queue = VecDeque::new();
+
+        for (idx, dist) in distance.iter_mut().enumerate() {
+            if self.on_edge(idx, maximizer, false) && self.board[idx] != other {
+                *dist = cost(idx);
+                if cost(idx) == 0 {
+                    queue.push_front(idx);
+                } else {
+                    queue.push_back(idx);
+                }
+            }
+        }
+
+        // Stones of one's own are free to pass through, so
+        // this is a breadth-first search with 0/1 weights.
+        while let Some(idx) = queue.pop_front() {
+            if self.on_edge(idx, maximizer, true) {
+                return Some(distance[idx]);
+            }
+            for next in self.neighbours(idx) {
+                if self.board[next] == other {
+                    continue;
+                }
+                let through = distance[idx] + cost(next);
+                if through < distance[next] {
+                    distance[next] = through;
+                    if cost(next) == 0 {
+                        queue.push_front(next);
+                    } else {
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        None
+    }
+}
+
+/// Endow upon Hex the ability to
+/// play games.
+impl GameStrategy for Hex {
+    /// The Player is a char.
+    type Player = char;
+
+    /// The Move is the index of the cell to place a stone on.
+    type Move = usize;
+
+    /// The Board is a flat vector of cells, row by row.
+    type Board = Vec<char>;
+
+    /// How many fewer stones the maximizer needs to connect
+    /// than the minimizer, or `WIN_SCORE` or `LOSS_SCORE`
+    /// once someone has.
+    fn evaluate(&self) -> f64 {
+        match self.get_winner() {
+            Some(ch) if ch == self.maximizer => Self::WIN_SCORE,
+            Some(_) => Self::LOSS_SCORE,
+            None => {
+                let unreachable = self.board.len() + 1;
+                let maximizer = self.distance_to_connect(true).unwrap_or(unreachable);
+                let minimizer = self.distance_to_connect(false).unwrap_or(unreachable);
+                minimizer as f64 - maximizer as f64
+            }
+        }
+    }
+
+    fn get_winner(&self) -> Option<Self::Player> {
+        if self.connects(true) {
+            Some(self.maximizer)
+        } else if self.connects(false) {
+            Some(self.minimizer)
+        } else {
+            None
+        }
+    }
+
+    /// A full board always holds exactly one connection.
+    fn is_game_tied(&self) -> bool {
+        debug_assert!(
+            self.board.contains(&self.default_char) || self.get_winner().is_some(),
+            "A full Hex board must have a winner."
+        );
+        false
+    }
+
+    fn is_game_complete(&self) -> bool {
+        self.get_winner().is_some()
+    }
+
+    fn get_available_moves(&self) -> Vec<Self::Move> {
+        (0..self.board.len())
+            .filter(|&idx| self.board[idx] == self.default_char)
+            .collect()
+    }
+
+    fn play(&mut self, &mv: &Self::Move, maximizer: bool) {
+        self.board[mv] = if maximizer {
+            self.maximizer
+        } else {
+            self.minimizer
+        };
+    }
+
+    fn clear(&mut self, &mv: &Self::Move) {
+        self.board[mv] = self.default_char;
+    }
+
+    fn reset(&mut self) {
+        self.board.fill(self.default_char);
+    }
+
+    /// The maximizer moves first, so it is to move
+    /// whenever an even number of cells are filled.
+    fn current_player(&self) -> bool {
+        let filled = self
+            .board
+            .iter()
+            .filter(|&&ch| ch != self.default_char)
+            .count();
+        filled % 2 == 0
+    }
+
+    fn get_board(&self) -> &Self::Board {
+        &self.board
+    }
+
+    fn board_dimensions(&self) -> Option<(usize, usize)> {
+        Some((self.size, self.size))
+    }
+
+    fn is_a_valid_move(&self, &mv: &Self::Move) -> bool {
+        mv < self.board.len() && self.board[mv] == self.default_char
+    }
+
+    fn get_a_sentinel_move(&self) -> Self::Move {
+        self.board.len()
+    }
+
+    fn position_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.board.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Try the cells nearest the center first.
+    fn order_moves(&self, mut moves: Vec<Self::Move>, _is_maximizing: bool) -> Vec<Self::Move> {
+        let center = self.size - 1;
+        moves.sort_by_key(|&idx| {
+            let (row, col) = (idx / self.size, idx % self.size);
+            (2 * row).abs_diff(center) + (2 * col).abs_diff(center)
+        });
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+
+    #[test]
+    fn straight_connections_are_detected() {
+        let mut hex = Hex::new(4);
+        for row in 0..3 {
+            hex.play(&(row * 4 + 1), true);
+        }
+        assert_eq!(hex.get_winner(), None);
+        assert_eq!(hex.distance_to_connect(true), Some(1));
+
+        hex.play(&13, true);
+        assert_eq!(hex.get_winner(), Some('o'));
+        assert!(hex.is_game_complete());
+
+        let mut hex = Hex::new(4);
+        for col in 0..4 {
+            hex.play(&(8 + col), false);
+        }
+        assert_eq!(hex.get_winner(), Some('x'));
+        assert_eq!(hex.evaluate(), Hex::LOSS_SCORE);
+    }
+
+    #[test]
+    fn connections_follow_hexagonal_neighbours() {
+        // Cells step down and to the left, which are
+        // neighbours on a rhombus of hexagons.
+        let mut hex = Hex::new(3);
+        for idx in [2, 4, 6] {
+            hex.play(&idx, true);
+        }
+        assert_eq!(hex.get_winner(), Some('o'));
+
+        // Stepping down and to the right isn't.
+        let mut hex = Hex::new(3);
+        for idx in [0, 4, 8] {
+            hex.play(&idx, true);
+        }
+        assert_eq!(hex.get_winner(), None);
+    }
+
+    #[test]
+    fn a_full_board_is_never_tied() {
+        let mut hex = Hex::new(3);
+        for idx in 0..9 {
+            hex.play(&idx, idx % 2 == 0);
+        }
+        assert!(hex.get_winner().is_some());
+        assert!(!hex.is_game_tied());
+    }
+
+    #[test]
+    fn engine_completes_a_connection() {
+        let mut hex = Hex::new(3);
+        hex.play(&1, true);
+        hex.play(&4, true);
+        hex.play(&0, false);
+        hex.play(&3, false);
+        // Both 6 and 7 touch 4 and the bottom edge.
+        let best_move = hex.get_best_move(1, false);
+        assert!([6, 7].contains(&best_move));
+    }
+}
@@ -0,0 +1,341 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use crate::strategy::game_strategy::GameStrategy;
+
+/// A game of Kalah, the most common Mancala game, where each
+/// player owns a row of houses and a store to their right.
+/// A move picks up every seed in one of the mover's houses
+/// and sows them one by one counterclockwise, skipping the
+/// opponent's store.
+///
+/// A last seed landing in the mover's store earns another
+/// move, and one landing in an empty house of the mover's
+/// captures it along with the seeds in the house opposite.
+/// The game ends once either row is empty, when each player
+/// keeps the seeds left on their side, and whoever has more
+/// seeds wins.
+///
+/// Pits are numbered counterclockwise from the maximizer's
+/// leftmost house: the maximizer's houses, their store,
+/// the minimizer's houses and then the minimizer's store.
+#[derive(Debug, Clone)]
+pub struct Kalah {
+    /// The number of houses on each side.
+    pub houses: usize,
+    /// The number of seeds each house starts with.
+    pub seeds: usize,
+    /// The number of seeds in each pit.
+    pub pits: Vec<usize>,
+    /// The pits before each move played so far, along
+    /// with whether the move passed the turn.
+    history: Vec<(Vec<usize>, bool)>,
+}
+
+impl Display for Kalah {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let row = |pits: &mut dyn Iterator<Item = &usize>| {
+            pits.map(|seeds| format!("{:>2}", seeds))
+                .collect::<Vec<_>>()
+                .join(" ")
+        };
+        let minimizer = &self.pits[self.houses + 1..self.minimizer_store()];
+        let maximizer = &self.pits[..self.houses];
+        writeln!(f, "   {}", row(&mut minimizer.iter().rev()))?;
+        writeln!(
+            f,
+            "{:>2} {} {:>2}",
+            self.pits[self.minimizer_store()],
+            "   ".repeat(self.houses),
+            self.pits[self.maximizer_store()]
+        )?;
+        writeln!(f, "   {}", row(&mut maximizer.iter()))
+    }
+}
+
+impl Default for Kalah {
+    fn default() -> Self {
+        Kalah::new(6, 4)
+    }
+}
+
+impl Kalah {
+    /// A game with `houses` houses on each side,
+    /// each starting with `seeds` seeds.
+    pub fn new(houses: usize, seeds: usize) -> Self {
+        assert!(houses > 0, "Need at least one house on each side.");
+        let mut pits = vec![seeds; 2 * houses + 2];
+        pits[houses] = 0;
+        pits[2 * houses + 1] = 0;
+        Self {
+            houses,
+            seeds,
+            pits,
+            history: vec![],
+        }
+    }
+
+    pub fn maximizer_store(&self) -> usize {
+        self.houses
+    }
+
+    pub fn minimizer_store(&self) -> usize {
+        2 * self.houses + 1
+    }
+
+    /// The pits holding a player's houses.
+    fn houses_of(&self, maximizer: bool) -> std::ops::Range<usize> {
+        if maximizer {
+            0..self.houses
+        } else {
+            self.houses + 1..self.minimizer_store()
+        }
+    }
+
+    fn store_of(&self, maximizer: bool) -> usize {
+        if maximizer {
+            self.maximizer_store()
+        } else {
+            self.minimizer_store()
+        }
+    }
+
+    /// The house across the board from a given one.
+    fn opposite(&self, house: usize) -> usize {
+        2 * self.houses - house
+    }
+
+    /// The pit the last seed sown from a given house lands in,
+    /// for the player that owns the house.
+    fn last_pit(&self, house: usize, maximizer: bool) -> usize {
+        let skipped = self.store_of(!maximizer);
+        let mut pit = house;
+        for _ in 0..self.pits[house] {
+            pit = (pit + 1) % self.pits.len();
+            if pit == skipped {
+                pit = (pit + 1) % self.pits.len();
+            }
+        }
+        pit
+    }
+
+    /// The seeds a player has, counting the ones
+    /// left on their side once the game is over.
+    pub fn seeds_of(&self, maximizer: bool) -> usize {
+        let stored = self.pits[self.store_of(maximizer)];
+        if self.is_game_complete() {
+            stored + self.pits[self.houses_of(maximizer)].iter().sum::<usize>()
+        } else {
+            stored
+        }
+    }
+}
+
+/// Endow upon Kalah the ability to
+/// play games.
+impl GameStrategy for Kalah {
+    /// The Player is true for the maximizer
+    /// and false for the minimizer.
+    type Player = bool;
+
+    /// The Move is the index of the house to sow from.
+    type Move = usize;
+
+    /// The Board is the number of seeds in each pit.
+    type Board = Vec<usize>;
+
+    /// The difference in stored seeds while the game is on,
+    /// and `WIN_SCORE` or `LOSS_SCORE` once it is over.
+    fn evaluate(&self) -> f64 {
+        if self.is_game_complete() {
+            return match self.get_winner() {
+                Some(true) => Self::WIN_SCORE,
+                Some(false) => Self::LOSS_SCORE,
+                None => 0.,
+            };
+        }
+        self.seeds_of(true) as f64 - self.seeds_of(false) as f64
+    }
+
+    /// Whoever has more seeds, once the game is over.
+    fn get_winner(&self) -> Option<Self::Player> {
+        if !self.is_game_complete() {
+            return None;
+        }
+        match self.seeds_of(true).cmp(&self.seeds_of(false)) {
+            std::cmp::Ordering::Greater => Some(true),
+            std::cmp::Ordering::Less => Some(false),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+
+    fn is_game_tied(&self) -> bool {
+        self.is_game_complete() && self.seeds_of(true) == self.seeds_of(false)
+    }
+
+    /// The game is over once either row of houses is empty.
+    fn is_game_complete(&self) -> bool {
+        [true, false]
+            .iter()
+            .any(|&maximizer| self.pits[self.houses_of(maximizer)].iter().all(|&s| s == 0))
+    }
+
+    fn get_available_moves(&self) -> Vec<Self::Move> {
+        if self.is_game_complete() {
+            return vec![];
+        }
+        self.houses_of(self.current_player())
+            .filter(|&house| self.pits[house] > 0)
+            .collect()
+    }
+
+    fn play(&mut self, &mv: &Self::Move, maximizer: bool) {
+        let passes_turn = self.move_passes_turn(&mv);
+        self.history.push((self.pits.clone(), passes_turn));
+
+        let skipped = self.store_of(!maximizer);
+        let mut pit = mv;
+        let mut seeds = std::mem::take(&mut self.pits[mv]);
+        while seeds > 0 {
+            pit = (pit + 1) % self.pits.len();
+            if pit == skipped {
+                continue;
+            }
+            self.pits[pit] += 1;
+            seeds -= 1;
+        }
+
+        if self.houses_of(maximizer).contains(&pit) && self.pits[pit] == 1 {
+            let opposite = self.opposite(pit);
+            if self.pits[opposite] > 0 {
+                let captured = self.pits[pit] + std::mem::take(&mut self.pits[opposite]);
+                self.pits[pit] = 0;
+                let store = self.store_of(maximizer);
+                self.pits[store] += captured;
+            }
+        }
+    }
+
+    /// Only the last move played can be cleared.
+    fn clear(&mut self, _mv: &Self::Move) {
+        if let Some((pits, _)) = self.history.pop() {
+            self.pits = pits;
+        }
+    }
+
+    fn reset(&mut self) {
+        *self = Kalah::new(self.houses, self.seeds);
+    }
+
+    /// The turn passes with every move whose
+    /// last seed doesn't land in the mover's store.
+    fn current_player(&self) -> bool {
+        let passes = self
+            .history
+            .iter()
+            .filter(|&&(_, passes_turn)| passes_turn)
+            .count();
+        passes % 2 == 0
+    }
+
+    fn get_board(&self) -> &Self::Board {
+        &self.pits
+    }
+
+    fn is_a_valid_move(&self, mv: &Self::Move) -> bool {
+        self.get_available_moves().contains(mv)
+    }
+
+    fn get_a_sentinel_move(&self) -> Self::Move {
+        self.pits.len()
+    }
+
+    fn position_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.pits.hash(&mut hasher);
+        self.current_player().hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Landing the last seed in one's own store earns another move.
+    fn move_passes_turn(&self, &mv: &Self::Move) -> bool {
+        let maximizer = self.houses_of(true).contains(&mv);
+        self.last_pit(mv, maximizer) != self.store_of(maximizer)
+    }
+
+    /// Try the moves earning another move first.
+    fn order_moves(&self, mut moves: Vec<Self::Move>, _is_maximizing: bool) -> Vec<Self::Move> {
+        moves.sort_by_key(|mv| self.move_passes_turn(mv));
+        moves
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+
+    #[test]
+    fn last_seed_in_the_store_earns_another_move() {
+        let mut game = Kalah::new(6, 4);
+        // Four seeds from the third house end in the store.
+        assert!(!game.move_passes_turn(&2));
+        game.play(&2, true);
+        assert_eq!(game.pits[..7], [4, 4, 0, 5, 5, 5, 1]);
+        assert!(game.current_player());
+
+        assert!(game.move_passes_turn(&0));
+        game.play(&0, true);
+        assert!(!game.current_player());
+        assert_eq!(game.get_available_moves(), (7..13).collect::<Vec<_>>());
+
+        game.clear(&0);
+        game.clear(&2);
+        assert_eq!(game.pits, Kalah::new(6, 4).pits);
+        assert!(game.current_player());
+    }
+
+    #[test]
+    fn landing_in_an_empty_house_captures_the_opposite_one() {
+        let mut game = Kalah::new(3, 0);
+        game.pits = vec![1, 0, 2, 0, 3, 1, 1, 0];
+        // The seed from the first house lands in the empty
+        // second one, across from the minimizer's second.
+        game.play(&0, true);
+        assert_eq!(game.pits, vec![0, 0, 2, 2, 3, 0, 1, 0]);
+        assert_eq!(game.evaluate(), 2.);
+
+        game.clear(&0);
+        assert_eq!(game.pits, vec![1, 0, 2, 0, 3, 1, 1, 0]);
+    }
+
+    #[test]
+    fn sowing_skips_the_opponents_store() {
+        let mut game = Kalah::new(2, 0);
+        game.pits = vec![0, 6, 0, 0, 0, 0];
+        game.play(&1, true);
+        assert_eq!(game.pits, vec![1, 1, 2, 1, 1, 0]);
+    }
+
+    #[test]
+    fn remaining_seeds_go_to_their_owner() {
+        let mut game = Kalah::new(2, 0);
+        game.pits = vec![0, 1, 3, 2, 0, 2];
+        game.play(&1, true);
+        assert!(game.is_game_complete());
+        assert_eq!((game.seeds_of(true), game.seeds_of(false)), (4, 4));
+        assert!(game.is_game_tied());
+    }
+
+    #[test]
+    fn engine_takes_the_extra_move() {
+        let mut game = Kalah::new(3, 0);
+        game.pits = vec![2, 0, 1, 0, 2, 2, 2, 0];
+        // Sowing the last house ends in the store, and sowing
+        // the first one then captures across from the last.
+        let variation = game.get_principal_variation(2, false);
+        assert_eq!(variation[..2], [2, 0]);
+        assert_eq!(game.next_state(&2, true).next_state(&0, true).pits[3], 4);
+    }
+}
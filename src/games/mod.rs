@@ -1,6 +1,46 @@
+mod grid;
+pub use grid::Grid;
+mod render;
+pub use render::render_grid;
 mod tic_tac_toe;
-pub use tic_tac_toe::TicTacToe;
+pub use tic_tac_toe::{MoveError, ParseBoardError, TicTacToe};
+#[cfg(feature = "std")]
+mod checkers;
+#[cfg(feature = "std")]
+pub use checkers::{Checkers, CheckersMove};
+#[cfg(feature = "std")]
+mod connect_four;
+#[cfg(feature = "std")]
+pub use connect_four::ConnectFour;
+#[cfg(feature = "std")]
+mod dots_and_boxes;
+#[cfg(feature = "std")]
+pub use dots_and_boxes::DotsAndBoxes;
+#[cfg(feature = "std")]
+mod gomoku;
+#[cfg(feature = "std")]
+pub use gomoku::Gomoku;
+#[cfg(feature = "std")]
+mod hex;
+#[cfg(feature = "std")]
+pub use hex::Hex;
+#[cfg(feature = "std")]
+mod kalah;
+#[cfg(feature = "std")]
+pub use kalah::Kalah;
+#[cfg(feature = "std")]
+mod nim;
+#[cfg(feature = "std")]
+pub use nim::Nim;
+#[cfg(feature = "std")]
+mod reversi;
+#[cfg(feature = "std")]
+pub use reversi::Reversi;
 #[cfg(feature = "chess")]
 mod chess;
 #[cfg(feature = "chess")]
 pub use chess::Chess;
+#[cfg(feature = "chess")]
+mod book;
+#[cfg(feature = "chess")]
+pub use book::Book;
@@ -0,0 +1,171 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use crate::strategy::game_strategy::GameStrategy;
+
+/// A game of Nim, where the players take turns removing
+/// any number of objects from a single heap. Under the
+/// normal play convention, whoever takes the last object wins.
+#[derive(Debug, Clone)]
+pub struct Nim {
+    pub heaps: Vec<usize>,
+    /// The moves played so far, along with
+    /// whether the maximizer played them.
+    history: Vec<((usize, usize), bool)>,
+}
+
+impl Display for Nim {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (idx, &heap) in self.heaps.iter().enumerate() {
+            writeln!(f, "{}: {}", idx, "|".repeat(heap))?;
+        }
+        Ok(())
+    }
+}
+
+impl Nim {
+    pub fn new(heaps: Vec<usize>) -> Self {
+        Self {
+            heaps,
+            history: vec![],
+        }
+    }
+
+    /// The nim-sum of the heaps. The player to move
+    /// can force a win exactly when this is non-zero.
+    pub fn nim_sum(&self) -> usize {
+        self.heaps.iter().fold(0, |acc, &heap| acc ^ heap)
+    }
+}
+
+/// Endow upon Nim the ability to
+/// play games.
+impl GameStrategy for Nim {
+    /// The Player is true for the maximizer
+    /// and false for the minimizer.
+    type Player = bool;
+
+    /// The Move is a pair of the heap to take from
+    /// and the number of objects to take from it.
+    type Move = (usize, usize);
+
+    /// The Board is the size of each heap.
+    type Board = Vec<usize>;
+
+    fn evaluate(&self) -> f64 {
+        match self.get_winner() {
+            Some(true) => Self::WIN_SCORE,
+            Some(false) => Self::LOSS_SCORE,
+            None => 0.,
+        }
+    }
+
+    /// Whoever took the last object, once none are left.
+    fn get_winner(&self) -> Option<Self::Player> {
+        if self.is_game_complete() {
+            self.history.last().map(|&(_, maximizer)| maximizer)
+        } else {
+            None
+        }
+    }
+
+    /// Someone always takes the last object.
+    fn is_game_tied(&self) -> bool {
+        false
+    }
+
+    fn is_game_complete(&self) -> bool {
+        self.heaps.iter().all(|&heap| heap == 0)
+    }
+
+    fn get_available_moves(&self) -> Vec<Self::Move> {
+        self.heaps
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, &heap)| (1..=heap).map(move |count| (idx, count)))
+            .collect()
+    }
+
+    fn play(&mut self, &(heap, count): &Self::Move, maximizer: bool) {
+        self.heaps[heap] -= count;
+        self.history.push(((heap, count), maximizer));
+    }
+
+    fn clear(&mut self, &(heap, count): &Self::Move) {
+        self.heaps[heap] += count;
+        self.history.pop();
+    }
+
+    /// Put back every object taken so far.
+    fn reset(&mut self) {
+        while let Some(((heap, count), _)) = self.history.pop() {
+            self.heaps[heap] += count;
+        }
+    }
+
+    /// Whoever didn't make the last move.
+    fn current_player(&self) -> bool {
+        !matches!(self.history.last(), Some(&(_, true)))
+    }
+
+    fn get_board(&self) -> &Self::Board {
+        &self.heaps
+    }
+
+    fn is_a_valid_move(&self, &(heap, count): &Self::Move) -> bool {
+        heap < self.heaps.len() && count >= 1 && count <= self.heaps[heap]
+    }
+
+    fn get_a_sentinel_move(&self) -> Self::Move {
+        (self.heaps.len(), 0)
+    }
+
+    fn position_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.heaps.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+
+    #[test]
+    fn available_moves_cover_every_removal() {
+        let nim = Nim::new(vec![2, 0, 1]);
+        assert_eq!(nim.get_available_moves(), vec![(0, 1), (0, 2), (2, 1)]);
+    }
+
+    #[test]
+    fn taking_the_last_object_wins() {
+        let mut nim = Nim::new(vec![1]);
+        nim.play(&(0, 1), false);
+        assert!(nim.is_game_complete());
+        assert_eq!(nim.get_winner(), Some(false));
+        assert_eq!(nim.evaluate(), -1000.);
+
+        nim.clear(&(0, 1));
+        assert_eq!(nim.get_winner(), None);
+    }
+
+    #[test]
+    fn engine_leaves_a_zero_nim_sum() {
+        let mut nim = Nim::new(vec![3, 4, 5]);
+        let mv = nim.get_best_move(12, false);
+        nim.play(&mv, true);
+        assert_eq!(nim.nim_sum(), 0);
+    }
+
+    #[test]
+    fn reset_restores_the_heaps() {
+        let mut nim = Nim::new(vec![3, 4, 5]);
+        nim.play(&(0, 2), true);
+        nim.play(&(2, 5), false);
+        nim.reset();
+        assert_eq!(nim.heaps, vec![3, 4, 5]);
+        assert_eq!(nim.get_winner(), None);
+    }
+}
@@ -0,0 +1,70 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt::Write;
+
+/// The letters naming a column, as moves are written:
+/// `a` to `z`, then `aa`, `ab`, and so on.
+pub(crate) fn column_label(col: usize) -> String {
+    let mut letters = Vec::new();
+    let mut n = col + 1;
+    while n > 0 {
+        n -= 1;
+        letters.push((b'a' + (n % 26) as u8) as char);
+        n /= 26;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Draw a grid of cells, row by row, `size` cells to a row,
+/// with column letters across the top and row numbers, from
+/// 1, down the side, so that a cell reads as e.g. `b2`.
+pub fn render_grid(board: &[char], size: usize) -> String {
+    let rows = board.len().checked_div(size).unwrap_or(0);
+    let labels: Vec<String> = (0..size).map(column_label).collect();
+    let row_width = rows.to_string().len();
+    let cell_width = labels.iter().map(String::len).max().unwrap_or(1);
+
+    let mut out = String::new();
+    out.push_str(&" ".repeat(row_width));
+    for label in &labels {
+        let _ = write!(out, " {:>width$}", label, width = cell_width);
+    }
+    out.push('\n');
+
+    for (row, cells) in board.chunks(size.max(1)).take(rows).enumerate() {
+        let _ = write!(out, "{:>width$}", row + 1, width = row_width);
+        for cell in cells {
+            let _ = write!(out, " {:>width$}", cell, width = cell_width);
+        }
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn a_grid_is_labelled_on_both_sides() {
+        let board: Vec<char> = "xo--x---o".chars().collect();
+        let render = render_grid(&board, 3);
+        let lines: Vec<&str> = render.lines().collect();
+        assert_eq!(lines, ["  a b c", "1 x o -", "2 - x -", "3 - - o"]);
+    }
+
+    #[test]
+    fn wide_grids_get_longer_labels() {
+        assert_eq!(column_label(0), "a");
+        assert_eq!(column_label(25), "z");
+        assert_eq!(column_label(26), "aa");
+
+        let board = vec!['-'; 27 * 10];
+        let render = render_grid(&board, 27);
+        let lines: Vec<&str> = render.lines().collect();
+        assert!(lines[0].starts_with("    a  b"));
+        assert!(lines[0].ends_with(" z aa"));
+        assert!(lines[10].starts_with("10  -  -"));
+    }
+}
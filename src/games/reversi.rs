@@ -0,0 +1,341 @@
+use std::collections::hash_map::DefaultHasher;
+use std::fmt::Display;
+use std::hash::{Hash, Hasher};
+
+use crate::strategy::game_strategy::GameStrategy;
+
+/// The side length of the board.
+const SIZE: usize = 8;
+
+/// The eight compass directions a line of discs can run in.
+const DIRECTIONS: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A game of Reversi (Othello), where placing a disc
+/// flips every line of opponent discs it brackets.
+///
+/// A player without any legal placement has to pass,
+/// which is represented by the `Reversi::PASS` move.
+#[derive(Debug, Clone)]
+pub struct Reversi {
+    pub board: Vec<char>,
+    pub default_char: char,
+    pub maximizer: char,
+    pub minimizer: char,
+    /// Whether it is the maximizer's turn to move.
+    pub maximizer_to_move: bool,
+    /// The moves played so far, along with the discs each one
+    /// flipped and whose turn it was, so they can be cleared.
+    history: Vec<(usize, Vec<usize>, bool)>,
+}
+
+impl Display for Reversi {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for row in self.board.chunks(SIZE) {
+            for &x in row.iter() {
+                write!(f, "{}", x)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for Reversi {
+    fn default() -> Self {
+        Reversi::new()
+    }
+}
+
+impl Reversi {
+    /// The move that passes the turn to the opponent.
+    pub const PASS: usize = SIZE * SIZE;
+
+    /// The standard starting position, with
+    /// the maximizer ('b', black) to move.
+    pub fn new() -> Self {
+        let mut board = vec!['-'; SIZE * SIZE];
+        let mid = SIZE / 2;
+        board[(mid - 1) * SIZE + (mid - 1)] = 'w';
+        board[(mid - 1) * SIZE + mid] = 'b';
+        board[mid * SIZE + (mid - 1)] = 'b';
+        board[mid * SIZE + mid] = 'w';
+
+        Self {
+            board,
+            default_char: '-',
+            maximizer: 'b',
+            minimizer: 'w',
+            maximizer_to_move: true,
+            history: vec![],
+        }
+    }
+
+    fn player(&self, maximizer: bool) -> char {
+        if maximizer {
+            self.maximizer
+        } else {
+            self.minimizer
+        }
+    }
+
+    /// The opponent discs that would be flipped
+    /// if `player` placed a disc at `idx`.
+    fn flips(&self, idx: usize, player: char) -> Vec<usize> {
+        let mut flipped = vec![];
+        if self.board[idx] != self.default_char {
+            return flipped;
+        }
+        let (row, col) = ((idx / SIZE) as isize, (idx % SIZE) as isize);
+
+        for &(d_row, d_col) in DIRECTIONS.iter() {
+            let mut line = vec![];
+            let (mut r, mut c) = (row + d_row, col + d_col);
+
+            while (0..SIZE as isize).contains(&r) && (0..SIZE as isize).contains(&c) {
+                let cell = r as usize * SIZE + c as usize;
+                let ch = self.board[cell];
+                if ch == player {
+                    flipped.extend(line);
+                    break;
+                } else if ch == self.default_char {
+                    break;
+                }
+                line.push(cell);
+                r += d_row;
+                c += d_col;
+            }
+        }
+        flipped
+    }
+
+    /// The cells where the given player may place a disc.
+    fn placements(&self, maximizer: bool) -> Vec<usize> {
+        let player = self.player(maximizer);
+        (0..SIZE * SIZE)
+            .filter(|&idx| !self.flips(idx, player).is_empty())
+            .collect()
+    }
+
+    fn count(&self, ch: char) -> usize {
+        self.board.iter().filter(|&&x| x == ch).count()
+    }
+}
+
+/// Endow upon Reversi the ability to
+/// play games.
+impl GameStrategy for Reversi {
+    /// The Player is a char.
+    /// Usually one of 'b', 'w'.
+    type Player = char;
+
+    /// The Move is a single number representing an
+    /// index of the Board vector, i.e. in range
+    /// `[0, 63]`, or `Reversi::PASS`.
+    type Move = usize;
+
+    /// The Board is a single vector of length 64.
+    type Board = Vec<char>;
+
+    /// A finished game scores `WIN_SCORE` or `LOSS_SCORE`, otherwise
+    /// the disc differential with each corner
+    /// counting for an extra ten discs.
+    fn evaluate(&self) -> f64 {
+        if self.is_game_complete() {
+            return match self.get_winner() {
+                Some(winner) if winner == self.maximizer => Self::WIN_SCORE,
+                Some(_) => Self::LOSS_SCORE,
+                None => 0.,
+            };
+        }
+
+        let last = SIZE - 1;
+        let corners = [0, last, last * SIZE, last * SIZE + last];
+        let weight = |idx: usize| if corners.contains(&idx) { 11. } else { 1. };
+
+        self.board
+            .iter()
+            .enumerate()
+            .map(|(idx, &ch)| {
+                if ch == self.maximizer {
+                    weight(idx)
+                } else if ch == self.minimizer {
+                    -weight(idx)
+                } else {
+                    0.
+                }
+            })
+            .sum()
+    }
+
+    fn get_winner(&self) -> Option<Self::Player> {
+        if !self.is_game_complete() {
+            return None;
+        }
+        let (max_count, min_count) = (self.count(self.maximizer), self.count(self.minimizer));
+        if max_count > min_count {
+            Some(self.maximizer)
+        } else if min_count > max_count {
+            Some(self.minimizer)
+        } else {
+            None
+        }
+    }
+
+    fn is_game_tied(&self) -> bool {
+        self.is_game_complete() && self.count(self.maximizer) == self.count(self.minimizer)
+    }
+
+    /// The game only ends once neither player can place a disc.
+    fn is_game_complete(&self) -> bool {
+        self.placements(true).is_empty() && self.placements(false).is_empty()
+    }
+
+    /// The placements for the player to move, or a
+    /// lone `Reversi::PASS` if they have none but
+    /// their opponent does.
+    fn get_available_moves(&self) -> Vec<Self::Move> {
+        self.moves_for(self.maximizer_to_move)
+    }
+
+    /// A player with nowhere to place a disc must pass,
+    /// unless neither player can place one.
+    fn moves_for(&self, maximizer: bool) -> Vec<Self::Move> {
+        let moves = self.placements(maximizer);
+        if moves.is_empty() && !self.placements(!maximizer).is_empty() {
+            vec![Self::PASS]
+        } else {
+            moves
+        }
+    }
+
+    fn play(&mut self, &mv: &Self::Move, maximizer: bool) {
+        // player: true means the maximizer's turn.
+        let mut flipped = vec![];
+
+        if mv != Self::PASS {
+            let player = self.player(maximizer);
+            flipped = self.flips(mv, player);
+            self.board[mv] = player;
+            for &idx in flipped.iter() {
+                self.board[idx] = player;
+            }
+        }
+        self.history.push((mv, flipped, self.maximizer_to_move));
+        self.maximizer_to_move = !maximizer;
+    }
+
+    fn clear(&mut self, &mv: &Self::Move) {
+        if let Some((played, flipped, maximizer_to_move)) = self.history.pop() {
+            assert_eq!(played, mv, "Moves must be cleared in reverse order.");
+
+            if played != Self::PASS {
+                let opponent = if self.board[played] == self.maximizer {
+                    self.minimizer
+                } else {
+                    self.maximizer
+                };
+                self.board[played] = self.default_char;
+                for idx in flipped {
+                    self.board[idx] = opponent;
+                }
+            }
+            self.maximizer_to_move = maximizer_to_move;
+        }
+    }
+
+    /// Set up the standard starting position again.
+    fn reset(&mut self) {
+        *self = Reversi::new();
+    }
+
+    fn current_player(&self) -> bool {
+        self.maximizer_to_move
+    }
+
+    fn get_board(&self) -> &Self::Board {
+        &self.board
+    }
+
+    fn board_dimensions(&self) -> Option<(usize, usize)> {
+        Some((SIZE, SIZE))
+    }
+
+    fn is_a_valid_move(&self, mv: &Self::Move) -> bool {
+        self.get_available_moves().contains(mv)
+    }
+
+    fn get_a_sentinel_move(&self) -> Self::Move {
+        Self::PASS + 1
+    }
+
+    fn position_hash(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.board.hash(&mut hasher);
+        self.maximizer_to_move.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+
+    #[test]
+    fn opening_moves() {
+        let game = Reversi::new();
+        assert_eq!(game.get_available_moves(), vec![19, 26, 37, 44]);
+        assert!(!game.is_game_complete());
+    }
+
+    #[test]
+    fn play_flips_and_clear_restores() {
+        let mut game = Reversi::new();
+        let start = game.board.clone();
+
+        game.play(&19, true);
+        assert_eq!(game.board[27], 'b');
+        assert_eq!(game.count('b'), 4);
+        assert!(!game.maximizer_to_move);
+
+        game.clear(&19);
+        assert_eq!(game.board, start);
+        assert!(game.maximizer_to_move);
+    }
+
+    #[test]
+    fn forced_pass() {
+        let mut game = Reversi::new();
+        game.board = vec!['-'; SIZE * SIZE];
+        game.board[0] = 'w';
+        game.board[1] = 'b';
+
+        // Black can't bracket the white corner disc, but white can
+        // still bracket the black one, so black has to pass.
+        assert_eq!(game.get_available_moves(), vec![Reversi::PASS]);
+        assert!(!game.is_game_complete());
+
+        game.play(&Reversi::PASS, true);
+        assert_eq!(game.get_available_moves(), vec![2]);
+
+        game.play(&2, false);
+        assert!(game.is_game_complete());
+        assert_eq!(game.get_winner(), Some('w'));
+    }
+
+    #[test]
+    fn engine_finds_a_move_from_the_opening() {
+        let mut game = Reversi::new();
+        let mv = game.get_best_move(2, false);
+        assert!(game.is_a_valid_move(&mv));
+    }
+}
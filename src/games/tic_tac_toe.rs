@@ -1,23 +1,107 @@
-use std::fmt::Display;
+use alloc::string::String;
+use alloc::sync::Arc;
+use alloc::{format, vec, vec::Vec};
+use core::fmt::{Debug, Display};
+use core::hash::{Hash, Hasher};
 
-use crate::strategy::game_strategy::GameStrategy;
+use crate::games::grid::Grid;
+use crate::games::render::column_label;
+use crate::strategy::game_strategy::{GameResult, GameStrategy, UndoError};
+use crate::strategy::scored::ScoredGameStrategy;
+use crate::strategy::zobrist::ZobristTable;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "std")]
+use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(try_from = "SerializedTicTacToe")
+)]
 pub struct TicTacToe {
-    pub board: Vec<char>,
+    pub board: Grid<char>,
+    /// The width of a row, i.e. the stride between rows
+    /// of the board vector. For square boards this is
+    /// the side length.
     pub size: usize,
+    pub rows: usize,
+    pub cols: usize,
     pub default_char: char,
     pub maximizer: char,
     pub minimizer: char,
+    /// The number of same symbols in a row needed to win.
+    pub win_length: usize,
+    /// Whether to search only the `canonical_moves`
+    /// at the root, skipping symmetric duplicates.
+    pub symmetric_root: bool,
+    /// Whether the maximizer moves first,
+    /// rather than the minimizer.
+    pub first_player: bool,
+    /// The moves played so far, in order.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    history: Vec<usize>,
+    /// The moves taken back with `undo_last`, most recent
+    /// last, along with whether the maximizer played them.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    redo_stack: Vec<(usize, bool)>,
+    /// The keys the position is hashed with,
+    /// for the maximizer's and the minimizer's symbols.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    zobrist: ZobristTable,
+    /// The Zobrist hash of the board, kept up to date
+    /// as moves are played and cleared.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    hash: u64,
+    /// How to score positions that aren't over yet,
+    /// if not simply as 0.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    evaluator: Option<Evaluator>,
 }
 
-impl Display for TicTacToe {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        for idx in 0..self.size {
-            let start = self.size * idx;
-            let end = self.size * (idx + 1);
-            let sub: &[char] = &self.board[start as usize..end as usize];
+/// A heuristic scoring unfinished TicTacToe positions
+/// from the maximizer's point of view.
+#[derive(Clone)]
+struct Evaluator(Arc<dyn Fn(&TicTacToe) -> f64 + Send + Sync>);
+
+impl Debug for Evaluator {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Evaluator(..)")
+    }
+}
+
+/// Two games are the same position if their boards and
+/// rules match, however they got there.
+impl PartialEq for TicTacToe {
+    fn eq(&self, other: &Self) -> bool {
+        self.board == other.board
+            && self.size == other.size
+            && self.win_length == other.win_length
+            && self.default_char == other.default_char
+            && self.maximizer == other.maximizer
+            && self.minimizer == other.minimizer
+            && self.first_player == other.first_player
+    }
+}
+
+impl Eq for TicTacToe {}
 
+impl Hash for TicTacToe {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.board.hash(state);
+        self.size.hash(state);
+        self.win_length.hash(state);
+        self.default_char.hash(state);
+        self.maximizer.hash(state);
+        self.minimizer.hash(state);
+        self.first_player.hash(state);
+    }
+}
+
+impl Display for TicTacToe {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for sub in self.board.chunks(self.cols) {
             for &x in sub.iter() {
                 write!(f, "{}", x)?;
             }
@@ -27,6 +111,187 @@ impl Display for TicTacToe {
     }
 }
 
+/// The ways a textual board can fail to describe a TicTacToe game.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseBoardError {
+    /// There were no rows at all.
+    Empty,
+    /// A row's length differs from that of the first row.
+    RaggedRow {
+        row: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The number of rows differs from their length.
+    NotSquare { rows: usize, cols: usize },
+    /// A cell is neither a player's symbol nor an empty cell.
+    UnknownChar(char),
+    /// The number of cells doesn't fit a board of the given size.
+    WrongLength { expected: usize, found: usize },
+    /// One player has made more than one move more than
+    /// the other, which no game played in turns can reach.
+    Unreachable { maximizer: usize, minimizer: usize },
+    /// Both players have a winning line, though
+    /// the game ends as soon as either has one.
+    TwoWinners,
+}
+
+impl Display for ParseBoardError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            ParseBoardError::Empty => write!(f, "The board has no rows."),
+            ParseBoardError::RaggedRow {
+                row,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Row {} has {} cells, but expected {}.",
+                row, found, expected
+            ),
+            ParseBoardError::NotSquare { rows, cols } => write!(
+                f,
+                "The board has {} rows of {} cells, but must be square.",
+                rows, cols
+            ),
+            ParseBoardError::UnknownChar(ch) => write!(f, "Unknown cell: '{}'.", ch),
+            ParseBoardError::WrongLength { expected, found } => write!(
+                f,
+                "The board has {} cells, but expected {}.",
+                found, expected
+            ),
+            ParseBoardError::Unreachable {
+                maximizer,
+                minimizer,
+            } => write!(
+                f,
+                "Players can't have made {} and {} moves in turn.",
+                maximizer, minimizer
+            ),
+            ParseBoardError::TwoWinners => write!(f, "Both players can't have won."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseBoardError {}
+
+/// The ways a move can fail to be played or cleared.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MoveError {
+    /// The index lies outside the board.
+    OutOfRange(usize),
+    /// The cell to play on already holds a symbol.
+    Occupied(usize),
+    /// The cell to clear holds no symbol.
+    Empty(usize),
+    /// The row and column lie outside the board.
+    OutOfBounds(usize, usize),
+}
+
+impl Display for MoveError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            MoveError::OutOfRange(idx) => write!(f, "Cell {} is off the board.", idx),
+            MoveError::Occupied(idx) => write!(f, "Cell {} is already taken.", idx),
+            MoveError::Empty(idx) => write!(f, "Cell {} has nothing to clear.", idx),
+            MoveError::OutOfBounds(row, col) => {
+                write!(f, "Cell ({}, {}) is off the board.", row, col)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for MoveError {}
+
+/// The serialized form of a TicTacToe game, checked for
+/// consistency before it becomes a game. The dimensions
+/// and win length may be left out for square boards.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct SerializedTicTacToe {
+    board: Vec<char>,
+    size: usize,
+    default_char: char,
+    maximizer: char,
+    minimizer: char,
+    #[serde(default)]
+    rows: Option<usize>,
+    #[serde(default)]
+    cols: Option<usize>,
+    #[serde(default)]
+    win_length: Option<usize>,
+    #[serde(default = "maximizer_first")]
+    first_player: bool,
+}
+
+#[cfg(feature = "serde")]
+fn maximizer_first() -> bool {
+    true
+}
+
+#[cfg(feature = "serde")]
+impl core::convert::TryFrom<SerializedTicTacToe> for TicTacToe {
+    type Error = String;
+
+    fn try_from(data: SerializedTicTacToe) -> Result<Self, Self::Error> {
+        let rows = data.rows.unwrap_or(data.size);
+        let cols = data.cols.unwrap_or(data.size);
+        if data.board.len() != rows * cols {
+            return Err(format!(
+                "The board has {} cells, but a {}x{} board needs {}.",
+                data.board.len(),
+                rows,
+                cols,
+                rows * cols
+            ));
+        }
+
+        let mut ttt = Self {
+            board: Grid::from_cells(rows, cols, data.board).unwrap(),
+            size: cols,
+            rows,
+            cols,
+            default_char: data.default_char,
+            maximizer: data.maximizer,
+            minimizer: data.minimizer,
+            win_length: data.win_length.unwrap_or_else(|| rows.min(cols)),
+            symmetric_root: false,
+            first_player: data.first_player,
+            history: vec![],
+            redo_stack: vec![],
+            zobrist: ZobristTable::new(rows * cols, 2),
+            hash: 0,
+            evaluator: None,
+        };
+        ttt.hash = ttt.full_hash();
+        Ok(ttt)
+    }
+}
+
+/// Reads a board the way `TicTacToe::from_str_board` does,
+/// so that e.g. `"xo-/-x-/---".parse::<TicTacToe>()` works.
+impl core::str::FromStr for TicTacToe {
+    type Err = ParseBoardError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        TicTacToe::from_str_board(s)
+    }
+}
+
+impl core::convert::TryFrom<&str> for TicTacToe {
+    type Error = ParseBoardError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        TicTacToe::from_str_board(s)
+    }
+}
+
+/// Sends a (row, col) cell of a board with the given
+/// rows and columns to its image under a symmetry.
+type Symmetry = fn(usize, usize, usize, usize) -> (usize, usize);
+
 impl Default for TicTacToe {
     fn default() -> Self {
         TicTacToe::new(3)
@@ -38,14 +303,126 @@ impl Default for TicTacToe {
 /// game.
 impl TicTacToe {
     pub fn new(size: usize) -> Self {
-        let board: Vec<char> = vec!['-'; (size * size) as usize];
+        Self::with_dimensions(size, size)
+    }
+
+    /// Create a board with the given number of rows and columns.
+    /// Winning takes a run as long as the shorter side,
+    /// unless configured otherwise with `with_win_length`.
+    pub fn with_dimensions(rows: usize, cols: usize) -> Self {
         Self {
-            board,
-            size,
+            board: Grid::new(rows, cols, '-'),
+            size: cols,
+            rows,
+            cols,
             default_char: '-',
             maximizer: 'o',
             minimizer: 'x',
+            win_length: rows.min(cols),
+            symmetric_root: false,
+            first_player: true,
+            history: vec![],
+            redo_stack: vec![],
+            zobrist: ZobristTable::new(rows * cols, 2),
+            hash: 0,
+            evaluator: None,
+        }
+    }
+
+    /// Create a game from its textual board, one row per line,
+    /// like the one `Display` produces, e.g. `"xo-\n-x-\noox"`,
+    /// or with rows separated by slashes, e.g. `"xo-/-x-/oox"`.
+    /// The symbols must be the default ones: 'o', 'x', and '-'.
+    pub fn from_str_board(s: &str) -> Result<TicTacToe, ParseBoardError> {
+        let rows: Vec<&str> = s.trim().split(['\n', '/']).map(str::trim).collect();
+        let size = rows.len();
+        if rows.iter().all(|row| row.is_empty()) {
+            return Err(ParseBoardError::Empty);
+        }
+
+        let mut ttt = TicTacToe::new(size);
+        let expected = rows[0].chars().count();
+        let mut board = Vec::with_capacity(size * size);
+
+        for (row_num, row) in rows.iter().enumerate() {
+            let found = row.chars().count();
+            if found != expected {
+                return Err(ParseBoardError::RaggedRow {
+                    row: row_num,
+                    expected,
+                    found,
+                });
+            }
+            for ch in row.chars() {
+                if ch != ttt.maximizer && ch != ttt.minimizer && ch != ttt.default_char {
+                    return Err(ParseBoardError::UnknownChar(ch));
+                }
+                board.push(ch);
+            }
+        }
+
+        if expected != size {
+            return Err(ParseBoardError::NotSquare {
+                rows: size,
+                cols: expected,
+            });
+        }
+        ttt.board = Grid::from_cells(size, size, board).unwrap();
+        ttt.hash = ttt.full_hash();
+        Ok(ttt)
+    }
+
+    /// Create a game of the given size from the cells of
+    /// a board, row by row, with the default symbols.
+    /// The board must pass `validate`.
+    pub fn with_board(board: Vec<char>, size: usize) -> Result<TicTacToe, ParseBoardError> {
+        let mut ttt = TicTacToe::new(size);
+        if board.len() != size * size {
+            return Err(ParseBoardError::WrongLength {
+                expected: size * size,
+                found: board.len(),
+            });
+        }
+        ttt.board = Grid::from_cells(size, size, board).unwrap();
+        ttt.validate()?;
+        ttt.hash = ttt.full_hash();
+        Ok(ttt)
+    }
+
+    /// Check that the board could have come about in a game:
+    /// every cell holds a known symbol, the players' moves
+    /// differ in number by at most one, as when taking turns,
+    /// and at most one player has completed a line.
+    pub fn validate(&self) -> Result<(), ParseBoardError> {
+        if let Some(&ch) = self
+            .board
+            .iter()
+            .find(|&&ch| ch != self.maximizer && ch != self.minimizer && ch != self.default_char)
+        {
+            return Err(ParseBoardError::UnknownChar(ch));
+        }
+
+        let maximizer = self
+            .board
+            .iter()
+            .filter(|&&ch| ch == self.maximizer)
+            .count();
+        let minimizer = self
+            .board
+            .iter()
+            .filter(|&&ch| ch == self.minimizer)
+            .count();
+        if maximizer.abs_diff(minimizer) > 1 {
+            return Err(ParseBoardError::Unreachable {
+                maximizer,
+                minimizer,
+            });
         }
+
+        if self.has_won(self.maximizer) && self.has_won(self.minimizer) {
+            return Err(ParseBoardError::TwoWinners);
+        }
+        Ok(())
     }
 
     pub fn with_player_1(self, character: char) -> Self {
@@ -60,12 +437,404 @@ impl TicTacToe {
             ..self
         }
     }
-    pub fn with_default_char(self, character: char) -> Self {
+    /// Use another symbol for empty cells,
+    /// including those already on the board.
+    pub fn with_default_char(mut self, character: char) -> Self {
+        for idx in 0..self.board.len() {
+            if self.board[idx] == self.default_char {
+                self.board[idx] = character;
+            }
+        }
         Self {
             default_char: character,
             ..self
         }
     }
+    pub fn with_win_length(self, win_length: usize) -> Self {
+        Self { win_length, ..self }
+    }
+    /// Score positions that aren't over yet with a heuristic,
+    /// e.g. `TicTacToe::open_lines`, so that shallow searches
+    /// on large boards still have something to go on.
+    /// Its scores should stay well within `WIN_SCORE`.
+    pub fn with_evaluator<F>(self, evaluator: F) -> Self
+    where
+        F: Fn(&TicTacToe) -> f64 + Send + Sync + 'static,
+    {
+        Self {
+            evaluator: Some(Evaluator(Arc::new(evaluator))),
+            ..self
+        }
+    }
+
+    /// A heuristic for `with_evaluator`: the number of lines
+    /// of `win_length` cells the maximizer could still complete,
+    /// less the number the minimizer could.
+    pub fn open_lines(&self) -> f64 {
+        let mut score = 0.;
+        for run in self.board.runs(self.win_length) {
+            let (mut maximizer, mut minimizer) = (false, false);
+            for idx in run {
+                maximizer |= self.board[idx] == self.maximizer;
+                minimizer |= self.board[idx] == self.minimizer;
+            }
+            if !minimizer {
+                score += 1.;
+            }
+            if !maximizer {
+                score -= 1.;
+            }
+        }
+        score
+    }
+
+    pub fn with_symmetric_root(self, symmetric_root: bool) -> Self {
+        Self {
+            symmetric_root,
+            ..self
+        }
+    }
+
+    /// Start the game with the minimizer to move
+    /// if `first_player` is false.
+    pub fn with_first_player(self, first_player: bool) -> Self {
+        Self {
+            first_player,
+            ..self
+        }
+    }
+
+    /// The cells each symmetry of the board sends every cell to:
+    /// the rotations and reflections of a square board, or the
+    /// reflections and half turn of a rectangular one.
+    fn symmetries(&self) -> Vec<Vec<usize>> {
+        let (rows, cols) = (self.rows, self.cols);
+        let mut transforms: Vec<Symmetry> = vec![
+            |r, c, _, _| (r, c),
+            |r, c, _, cols| (r, cols - 1 - c),
+            |r, c, rows, _| (rows - 1 - r, c),
+            |r, c, rows, cols| (rows - 1 - r, cols - 1 - c),
+        ];
+        if rows == cols {
+            transforms.extend([
+                (|r, c, _, _| (c, r)) as Symmetry,
+                |r, c, _, cols| (cols - 1 - c, r),
+                |r, c, rows, _| (c, rows - 1 - r),
+                |r, c, rows, cols| (cols - 1 - c, rows - 1 - r),
+            ]);
+        }
+
+        transforms
+            .into_iter()
+            .map(|transform| {
+                (0..rows * cols)
+                    .map(|idx| {
+                        let (row, col) = self.to_coordinates(idx);
+                        let (row, col) = transform(row, col, rows, cols);
+                        self.to_index(row, col)
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// The available moves, keeping only one of every set of
+    /// moves that a symmetry of the current board maps onto
+    /// each other, since they lead to equivalent positions.
+    pub fn canonical_moves(&self) -> Vec<usize> {
+        let preserving: Vec<Vec<usize>> = self
+            .symmetries()
+            .into_iter()
+            .filter(|map| (0..self.board.len()).all(|idx| self.board[map[idx]] == self.board[idx]))
+            .collect();
+
+        self.get_available_moves()
+            .into_iter()
+            .filter(|&mv| preserving.iter().all(|map| map[mv] >= mv))
+            .collect()
+    }
+
+    /// The exact minimax value, from the maximizer's point of view,
+    /// of every position reachable from the current one, by its
+    /// `position_hash`. Only finished games are scored, so this
+    /// is only feasible for boards as small as the usual 3x3.
+    #[cfg(feature = "std")]
+    pub fn solve_all(&self) -> HashMap<u64, f64> {
+        let mut solution = HashMap::new();
+        self.clone().solve_into(&mut solution);
+        solution
+    }
+
+    /// The value of the current position with perfect play,
+    /// after solving it and every position reachable from it
+    /// into `solution`, unless they're there already.
+    #[cfg(feature = "std")]
+    fn solve_into(&mut self, solution: &mut HashMap<u64, f64>) -> f64 {
+        let hash = self.position_hash();
+        if let Some(&value) = solution.get(&hash) {
+            return value;
+        }
+        let value = if self.is_game_complete() {
+            self.evaluate()
+        } else {
+            let maximizer = self.current_player();
+            let values = self.get_available_moves().into_iter().map(|mv| {
+                self.play(&mv, maximizer);
+                let value = self.solve_into(solution);
+                self.clear(&mv);
+                value
+            });
+            if maximizer {
+                values.fold(f64::NEG_INFINITY, f64::max)
+            } else {
+                values.fold(f64::INFINITY, f64::min)
+            }
+        };
+        solution.insert(hash, value);
+        value
+    }
+
+    /// The move that keeps the best value in `solution` for the
+    /// player to move, the first one found among any tied, or
+    /// `None` if the game is over or a position it leads to
+    /// is missing from `solution`.
+    #[cfg(feature = "std")]
+    pub fn best_move_from_solution(&self, solution: &HashMap<u64, f64>) -> Option<usize> {
+        if self.is_game_complete() {
+            return None;
+        }
+        let maximizer = self.current_player();
+        let mut game = self.clone();
+        let mut best: Option<(usize, f64)> = None;
+        for mv in self.get_available_moves() {
+            game.play(&mv, maximizer);
+            let value = *solution.get(&game.position_hash())?;
+            game.clear(&mv);
+            let better = match best {
+                None => true,
+                Some((_, best_value)) if maximizer => value > best_value,
+                Some((_, best_value)) => value < best_value,
+            };
+            if better {
+                best = Some((mv, value));
+            }
+        }
+        best.map(|(mv, _)| mv)
+    }
+
+    /// The Zobrist key of a player's symbol on a given cell.
+    fn zobrist_key(&self, idx: usize, maximizer: bool) -> u64 {
+        self.zobrist.key(idx, if maximizer { 0 } else { 1 })
+    }
+
+    /// Hash the whole board from scratch.
+    fn full_hash(&self) -> u64 {
+        self.board
+            .iter()
+            .enumerate()
+            .filter(|&(_, &ch)| ch != self.default_char)
+            .fold(0, |hash, (idx, &ch)| {
+                hash ^ self.zobrist_key(idx, ch == self.maximizer)
+            })
+    }
+
+    /// Read a cell given either as `row,col`, counting from zero
+    /// (e.g. `"1,2"`), or spreadsheet-style as column letters
+    /// followed by a row number counting from one (e.g. `"c2"`),
+    /// and turn it into a flat index. Cells off the board are `None`.
+    pub fn parse_move(&self, s: &str) -> Option<usize> {
+        let s = s.trim();
+        let (row, col) = if let Some((row, col)) = s.split_once(',') {
+            (
+                row.trim().parse::<usize>().ok()?,
+                col.trim().parse::<usize>().ok()?,
+            )
+        } else {
+            let split = s.find(|ch: char| !ch.is_ascii_alphabetic())?;
+            let (letters, digits) = s.split_at(split);
+            if letters.is_empty() {
+                return None;
+            }
+            let col = letters.chars().try_fold(0usize, |col, ch| {
+                let digit = ch.to_ascii_lowercase() as usize - 'a' as usize + 1;
+                col.checked_mul(26)?.checked_add(digit)
+            })?;
+            let row = digits.parse::<usize>().ok()?.checked_sub(1)?;
+            (row, col - 1)
+        };
+
+        if row < self.rows && col < self.cols {
+            Some(self.to_index(row, col))
+        } else {
+            None
+        }
+    }
+
+    /// Play a move like `play` does, but refuse
+    /// moves that are off the board or on a taken cell.
+    pub fn try_play(&mut self, mv: usize, maximizer: bool) -> Result<(), MoveError> {
+        if mv >= self.board.len() {
+            return Err(MoveError::OutOfRange(mv));
+        }
+        if self.board[mv] != self.default_char {
+            return Err(MoveError::Occupied(mv));
+        }
+        self.play(&mv, maximizer);
+        Ok(())
+    }
+
+    /// Clear a move like `clear` does, but refuse
+    /// moves that are off the board or on an empty cell.
+    pub fn try_clear(&mut self, mv: usize) -> Result<(), MoveError> {
+        if mv >= self.board.len() {
+            return Err(MoveError::OutOfRange(mv));
+        }
+        if self.board[mv] == self.default_char {
+            return Err(MoveError::Empty(mv));
+        }
+        self.clear(&mv);
+        Ok(())
+    }
+
+    /// Take back the most recently played move, returning it.
+    /// It can be played again with `redo`.
+    pub fn undo_last(&mut self) -> Option<usize> {
+        let mv = *self.history.last()?;
+        let maximizer = self.board[mv] == self.maximizer;
+        self.clear(&mv);
+        self.redo_stack.push((mv, maximizer));
+        Some(mv)
+    }
+
+    /// Play again the most recently undone move, returning it.
+    pub fn redo(&mut self) -> Option<usize> {
+        let (mv, maximizer) = self.redo_stack.pop()?;
+        let redo_stack = core::mem::take(&mut self.redo_stack);
+        self.play(&mv, maximizer);
+        self.redo_stack = redo_stack;
+        Some(mv)
+    }
+
+    /// The moves played so far, in the order they were played.
+    pub fn move_log(&self) -> &[usize] {
+        &self.history
+    }
+
+    /// The moves played so far as a numbered sequence of
+    /// the symbol played and the cell it was played on,
+    /// e.g. `"1. o b2, 2. x a1"`.
+    pub fn to_transcript(&self) -> String {
+        self.history
+            .iter()
+            .enumerate()
+            .map(|(turn, &mv)| {
+                let (row, col) = self.to_coordinates(mv);
+                format!(
+                    "{}. {} {}{}",
+                    turn + 1,
+                    self.board[mv],
+                    column_label(col),
+                    row + 1
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+
+    /// The flat index of the cell at a given row and column.
+    pub fn to_index(&self, row: usize, col: usize) -> usize {
+        self.board.to_index(row, col)
+    }
+
+    /// The row and column of the cell at a given flat index.
+    pub fn to_coordinates(&self, idx: usize) -> (usize, usize) {
+        (self.board.row(idx), self.board.col(idx))
+    }
+
+    /// The symbol at a given row and column,
+    /// or `None` if the cell is off the board.
+    pub fn get(&self, row: usize, col: usize) -> Option<char> {
+        self.board.get(row, col).copied()
+    }
+
+    /// Put a symbol at a given row and column, outside of
+    /// the history of moves, e.g. to set up a position.
+    /// The moves played so far are forgotten, since they no
+    /// longer lead to the board, which is then checked in
+    /// full for a winner.
+    pub fn set(&mut self, row: usize, col: usize, ch: char) -> Result<(), MoveError> {
+        if self.board.set(row, col, ch).is_none() {
+            return Err(MoveError::OutOfBounds(row, col));
+        }
+        self.history.clear();
+        self.redo_stack.clear();
+        self.hash = self.full_hash();
+        Ok(())
+    }
+
+    /// The symbol at a row and column that may
+    /// lie off the board on either side.
+    fn get_signed(&self, row: isize, col: isize) -> Option<char> {
+        self.board.get_signed(row, col).copied()
+    }
+
+    /// Scan every row, column, and diagonal for a winner.
+    fn scan_winner(&self) -> Option<char> {
+        let mut winner = self.check_diagonals();
+
+        if winner == self.default_char {
+            winner = self.check_rows();
+        }
+        if winner == self.default_char {
+            winner = self.check_cols();
+        }
+        if winner == self.default_char {
+            None
+        } else {
+            Some(winner)
+        }
+    }
+
+    /// Check only the row, column, and two diagonals
+    /// through a given cell for a winner, which is enough
+    /// to tell if the move played there won the game.
+    fn winner_through(&self, idx: usize) -> Option<char> {
+        let ch = self.board[idx];
+        if ch == self.default_char {
+            return None;
+        }
+        let (row, col) = self.to_coordinates(idx);
+        let (row, col) = (row as isize, col as isize);
+
+        let run_towards = |d_row: isize, d_col: isize| {
+            let (mut r, mut c) = (row + d_row, col + d_col);
+            let mut run = 0;
+            while self.get_signed(r, c) == Some(ch) {
+                run += 1;
+                r += d_row;
+                c += d_col;
+            }
+            run
+        };
+
+        [(0, 1), (1, 0), (1, 1), (1, -1)]
+            .iter()
+            .any(|&(d_row, d_col)| {
+                1 + run_towards(d_row, d_col) + run_towards(-d_row, -d_col) >= self.win_length
+            })
+            .then_some(ch)
+    }
+
+    /// The cells of the run that won the game, if it is won.
+    /// Runs are looked for from each cell in turn, row by row,
+    /// along the row, the column, and then the two diagonals,
+    /// and the first one found is returned.
+    pub fn winning_line(&self) -> Option<Vec<usize>> {
+        self.board.runs(self.win_length).find(|run| {
+            let ch = self.board[run[0]];
+            ch != self.default_char && run.iter().all(|&idx| self.board[idx] == ch)
+        })
+    }
 
     /// Check the main and anti-diagonals
     /// for a winner.
@@ -85,7 +854,7 @@ impl TicTacToe {
     pub fn check_rows(&self) -> char {
         let mut winner = self.default_char;
 
-        for row in 0..self.size as usize {
+        for row in 0..self.rows {
             if self.check_row(self.maximizer, row) {
                 winner = self.maximizer;
                 break;
@@ -101,7 +870,7 @@ impl TicTacToe {
     pub fn check_cols(&self) -> char {
         let mut winner = self.default_char;
 
-        for col in 0..self.size as usize {
+        for col in 0..self.cols {
             if self.check_col(self.maximizer, col) {
                 winner = self.maximizer;
                 break;
@@ -113,47 +882,55 @@ impl TicTacToe {
         winner
     }
 
+    /// Check every row, column, and diagonal
+    /// if a given player has won.
+    fn has_won(&self, ch: char) -> bool {
+        (0..self.rows).any(|row| self.check_row(ch, row))
+            || (0..self.cols).any(|col| self.check_col(ch, col))
+            || self.check_diagonal(ch, true)
+            || self.check_diagonal(ch, false)
+    }
+
     /// Check a given column if a given player has won.
     fn check_col(&self, ch: char, col_num: usize) -> bool {
-        for row in 0..self.size as usize {
-            if self.board[self.size as usize * row + col_num] != ch {
-                return false;
-            }
-        }
-        true
+        self.check_line(ch, 0, col_num, 1, 0)
     }
 
     /// Check a given row if a given player has won.
     fn check_row(&self, ch: char, row_num: usize) -> bool {
-        for col in 0..self.size as usize {
-            if self.board[self.size as usize * row_num + col] != ch {
-                return false;
-            }
-        }
-        true
+        self.check_line(ch, row_num, 0, 0, 1)
     }
 
-    /// Check the main and anti diagonals if a
-    /// given player has won.
+    /// Check every diagonal running in the direction of the
+    /// main or anti diagonal if a given player has won.
     fn check_diagonal(&self, ch: char, diag: bool) -> bool {
         // main diagonal is represented by true.
-        if diag {
-            for idx in 0..self.size as usize {
-                if self.board[(self.size as usize * idx as usize) + idx] != ch {
-                    return false;
-                }
-            }
-            true
+        let (first_col, d_col) = if diag {
+            (0, 1)
         } else {
-            for idx in 0..self.size as usize {
-                if self.board[(self.size as usize * (self.size as usize - 1 - idx as usize)) + idx]
-                    != ch
-                {
-                    return false;
+            (self.cols.saturating_sub(1), -1)
+        };
+
+        (0..self.cols).any(|col| self.check_line(ch, 0, col, 1, d_col))
+            || (1..self.rows).any(|row| self.check_line(ch, row, first_col, 1, d_col))
+    }
+
+    /// Walk the line starting at a given cell in a given
+    /// direction, and check if a given player has a run
+    /// of `win_length` symbols anywhere along it.
+    fn check_line(&self, ch: char, row: usize, col: usize, d_row: isize, d_col: isize) -> bool {
+        let mut run = 0;
+        for idx in self.board.line(row, col, d_row, d_col) {
+            if self.board[idx] == ch {
+                run += 1;
+                if run >= self.win_length {
+                    return true;
                 }
+            } else {
+                run = 0;
             }
-            true
         }
+        false
     }
 }
 
@@ -166,83 +943,204 @@ impl GameStrategy for TicTacToe {
 
     /// The Move is a single number representing an
     /// index of the Board vector, i.e. in range
-    /// `[0, (size * size) - 1]`.
+    /// `[0, (rows * cols) - 1]`.
     type Move = usize;
 
-    /// The Board is a single vector of length `size * size`.
-    type Board = Vec<char>;
+    /// The Board is a single vector of length `rows * cols`.
+    type Board = Grid<char>;
 
+    /// Unfinished positions score 0 unless
+    /// an evaluator was given with `with_evaluator`.
     fn evaluate(&self) -> f64 {
-        if self.is_game_tied() {
-            0.
-        } else {
-            let _winner = self.get_winner().unwrap();
-            if _winner == self.maximizer {
-                1000.
-            } else {
-                -1000.
-            }
+        match self.get_winner() {
+            Some(winner) if winner == self.maximizer => Self::WIN_SCORE,
+            Some(_) => Self::LOSS_SCORE,
+            None => match &self.evaluator {
+                Some(Evaluator(evaluator)) if !self.is_game_complete() => evaluator(self),
+                _ => 0.,
+            },
         }
     }
 
+    /// Only the lines through the last move played are checked,
+    /// since any earlier win would have ended the game. Boards
+    /// with cells filled other than by playing moves, e.g. loaded
+    /// with `with_board` or parsed, are scanned in full.
     fn get_winner(&self) -> Option<Self::Player> {
-        let mut winner = self.check_diagonals();
-
-        if winner == self.default_char {
-            winner = self.check_rows();
-        }
-        if winner == self.default_char {
-            winner = self.check_cols();
+        match self.history.last() {
+            Some(&idx) if self.history.len() == self.ply() => self.winner_through(idx),
+            _ => self.scan_winner(),
         }
-        Some(winner)
     }
 
     fn is_game_tied(&self) -> bool {
-        let _winner = self.get_winner().unwrap();
-
-        _winner == self.default_char && self.get_available_moves().is_empty()
+        self.get_winner().is_none() && self.legal_move_count() == 0
     }
 
     fn is_game_complete(&self) -> bool {
-        let _winner = self.get_winner();
-
-        self.get_available_moves().is_empty() || _winner.unwrap() != '-'
+        self.legal_move_count() == 0 || self.get_winner().is_some()
     }
 
     fn get_available_moves(&self) -> Vec<Self::Move> {
         let mut moves: Vec<usize> = vec![];
-        for idx in 0..(self.size * self.size) as usize {
-            if self.board[idx] == '-' {
+        self.fill_available_moves(&mut moves);
+        moves
+    }
+
+    fn fill_available_moves(&self, moves: &mut Vec<Self::Move>) {
+        moves.clear();
+        for idx in 0..self.rows * self.cols {
+            if self.board[idx] == self.default_char {
                 moves.push(idx)
             }
         }
-        moves
     }
 
+    /// Counts the empty cells without collecting them.
+    fn legal_move_count(&self) -> usize {
+        self.board
+            .iter()
+            .filter(|&&ch| ch == self.default_char)
+            .count()
+    }
+
+    /// Every move fills a cell, so the game
+    /// lasts at most as many moves as are empty.
+    fn max_depth_hint(&self) -> Option<i64> {
+        Some(self.legal_move_count() as i64)
+    }
+
+    /// Moves off the board, e.g. the sentinel, are ignored;
+    /// `try_play` reports them instead.
     fn play(&mut self, &mv: &Self::Move, maximizer: bool) {
         // player: true means the maximizer's turn.
+        if mv >= self.board.len() {
+            return;
+        }
 
         if maximizer {
             self.board[mv] = self.maximizer;
         } else {
             self.board[mv] = self.minimizer;
         }
+        self.hash ^= self.zobrist_key(mv, maximizer);
+        self.history.push(mv);
+        self.redo_stack.clear();
     }
 
+    /// Moves off the board are ignored;
+    /// `try_clear` reports them instead.
     fn clear(&mut self, &mv: &Self::Move) {
-        self.board[mv] = self.default_char
+        if mv >= self.board.len() {
+            return;
+        }
+        if self.board[mv] != self.default_char {
+            self.hash ^= self.zobrist_key(mv, self.board[mv] == self.maximizer);
+        }
+        self.board[mv] = self.default_char;
+        if let Some(pos) = self.history.iter().rposition(|&idx| idx == mv) {
+            self.history.remove(pos);
+        }
+    }
+
+    fn undo_move(&mut self) -> Result<(), UndoError> {
+        let &mv = self.history.last().ok_or(UndoError::NothingToUndo)?;
+        self.clear(&mv);
+        Ok(())
+    }
+
+    fn get_root_moves(&self) -> Vec<Self::Move> {
+        if self.symmetric_root {
+            self.canonical_moves()
+        } else {
+            self.get_available_moves()
+        }
+    }
+
+    /// Empty the board and forget every move played,
+    /// keeping the dimensions, players, and evaluator.
+    fn reset(&mut self) {
+        self.board.fill(self.default_char);
+        self.history.clear();
+        self.redo_stack.clear();
+        self.hash = 0;
+    }
+
+    /// The first player is to move whenever
+    /// an even number of cells are filled.
+    fn current_player(&self) -> bool {
+        (self.ply() % 2 != 1) == self.first_player
+    }
+
+    /// Every move fills a cell, so the plies
+    /// played are the cells filled.
+    fn ply(&self) -> usize {
+        self.board
+            .iter()
+            .filter(|&&ch| ch != self.default_char)
+            .count()
     }
 
     fn get_board(&self) -> &Self::Board {
         &self.board
     }
 
+    fn board_dimensions(&self) -> Option<(usize, usize)> {
+        Some((self.rows, self.cols))
+    }
+
     fn is_a_valid_move(&self, &mv: &Self::Move) -> bool {
-        self.board[mv] == self.default_char
+        mv < self.board.len() && self.board[mv] == self.default_char
     }
 
     fn get_a_sentinel_move(&self) -> Self::Move {
-        self.size * self.size + 1
+        self.rows * self.cols + 1
+    }
+
+    /// The Zobrist hash of the board, updated incrementally
+    /// by `play` and `clear` rather than recomputed.
+    fn position_hash(&self) -> u64 {
+        self.hash
+    }
+
+    /// Center cells score highest, then corners, then
+    /// everything else, since the first lie on the most lines.
+    fn score_move(&self, &mv: &Self::Move) -> i32 {
+        let (row, col) = self.to_coordinates(mv);
+        let is_central = |x: usize, len: usize| len.saturating_sub(1) / 2 <= x && x <= len / 2;
+        let is_edge = |x: usize, len: usize| x == 0 || x + 1 == len;
+
+        if is_central(row, self.rows) && is_central(col, self.cols) {
+            2
+        } else if is_edge(row, self.rows) && is_edge(col, self.cols) {
+            1
+        } else {
+            0
+        }
+    }
+
+    fn result(&self) -> Option<GameResult> {
+        match self.get_winner() {
+            Some(winner) if winner == self.maximizer => Some(GameResult::Player1Win),
+            Some(winner) if winner == self.minimizer => Some(GameResult::Player2Win),
+            _ if self.is_game_tied() => Some(GameResult::Draw),
+            _ => None,
+        }
+    }
+}
+
+/// TicTacToe is only ever won, lost, or drawn,
+/// so its scores are exact integers.
+impl ScoredGameStrategy for TicTacToe {
+    type Score = i32;
+
+    /// The same decisive scores as `evaluate`, as integers.
+    fn score(&self) -> i32 {
+        match self.get_winner() {
+            Some(winner) if winner == self.maximizer => <Self as GameStrategy>::WIN_SCORE as i32,
+            Some(_) => <Self as GameStrategy>::LOSS_SCORE as i32,
+            None => 0,
+        }
     }
 }
 
@@ -268,14 +1166,1084 @@ mod tests {
     #[test]
     fn test_should_always_tie_a_3_by_3_after_9_moves_at_depth_9() {
         let mut ttt = TicTacToe::new(3);
-        for move_number in 0..=8 {
-            let is_maximising = move_number % 2 == 0;
-            let i = ttt.get_best_move(9, is_maximising);
-            ttt.play(&i, is_maximising);
+        for _ in 0..=8 {
+            let i = ttt.get_best_move_for_current_player(9);
+            ttt.play(&i, ttt.current_player());
             println!("{}", ttt);
             // ttt.print_board();
         }
         assert!(ttt.is_game_complete());
         assert!(ttt.is_game_tied());
     }
+
+    #[test]
+    fn no_winner_on_an_empty_board() {
+        let mut ttt = TicTacToe::new(3);
+        assert_eq!(ttt.get_winner(), None);
+        assert!(!ttt.is_game_complete());
+
+        for (mv, maximizer) in [(0, true), (3, false), (1, true), (4, false), (2, true)] {
+            ttt.play(&mv, maximizer);
+        }
+        assert_eq!(ttt.get_winner(), Some('o'));
+        assert!(ttt.is_game_complete());
+    }
+
+    #[test]
+    fn result_covers_every_outcome() {
+        assert_eq!(TicTacToe::new(3).result(), None);
+
+        let player_1 = TicTacToe::from_str_board("ooo\nxx-\n---").unwrap();
+        assert_eq!(player_1.result(), Some(GameResult::Player1Win));
+
+        let player_2 = TicTacToe::from_str_board("xxx\noo-\no--").unwrap();
+        assert_eq!(player_2.result(), Some(GameResult::Player2Win));
+
+        let draw = TicTacToe::from_str_board("oxo\noxx\nxox").unwrap();
+        assert_eq!(draw.result(), Some(GameResult::Draw));
+    }
+
+    #[test]
+    fn iterative_deepening_agrees_with_fixed_depth() {
+        let mut ttt = TicTacToe::new(3);
+        ttt.play(&4, true);
+        ttt.play(&0, false);
+        ttt.play(&8, true);
+
+        let expected = ttt.get_best_move(6, true);
+        assert_eq!(ttt.get_best_move_iterative(6, true), expected);
+    }
+
+    #[test]
+    fn forced_win_scores_near_win_score() {
+        let mut ttt = TicTacToe::new(3);
+        ttt.play(&0, true);
+        ttt.play(&3, false);
+        ttt.play(&1, true);
+        ttt.play(&4, false);
+
+        let (best_move, score) = ttt.get_best_move_with_score(9, false);
+        assert_eq!(best_move, 2);
+        assert!((score - 1000.).abs() < 10.);
+    }
+
+    #[test]
+    fn move_ordering_reduces_nodes() {
+        use crate::strategy::counting::CountingGame;
+
+        let ttt = TicTacToe::new(3);
+
+        let mut unordered = CountingGame::without_ordering(ttt.clone());
+        unordered.get_best_move(9, true);
+
+        let mut ordered = CountingGame::new(ttt);
+        ordered.get_best_move(9, true);
+
+        assert!(ordered.nodes < unordered.nodes);
+    }
+
+    #[test]
+    fn pvs_agrees_with_alpha_beta_on_fewer_nodes() {
+        use crate::strategy::counting::CountingGame;
+
+        let mut ttt = TicTacToe::new(4);
+        ttt.play(&5, true);
+        ttt.play(&10, false);
+        let mut plain = CountingGame::new(ttt.clone());
+        let plain_move = plain.get_best_move(6, false);
+
+        let mut pvs = CountingGame::new(ttt);
+        let pvs_move = pvs.get_best_move_pvs(6, false);
+
+        assert_eq!(pvs_move, plain_move);
+        assert!(pvs.nodes <= plain.nodes);
+    }
+
+    #[test]
+    fn incremental_winner_check_agrees_with_full_scan_on_6_by_6() {
+        let mut ttt = TicTacToe::new(6).with_win_length(4);
+        for (mv, maximizer) in [(14, true), (0, false), (15, true), (35, false), (16, true)] {
+            ttt.play(&mv, maximizer);
+        }
+
+        for mv in ttt.get_available_moves() {
+            ttt.play(&mv, false);
+            assert_eq!(ttt.get_winner(), ttt.scan_winner());
+            ttt.clear(&mv);
+        }
+        // Completing 13, 14, 15, 16 wins, whatever else was played.
+        ttt.play(&13, true);
+        assert_eq!(ttt.get_winner(), Some('o'));
+        assert_eq!(ttt.scan_winner(), Some('o'));
+    }
+
+    #[test]
+    fn out_of_range_moves_are_refused_without_panicking() {
+        let mut ttt = position(&[4]);
+        assert!(!ttt.is_a_valid_move(&usize::MAX));
+        assert!(!ttt.is_a_valid_move(&9));
+        assert!(!ttt.is_a_valid_move(&4));
+        assert!(ttt.is_a_valid_move(&8));
+
+        assert_eq!(
+            ttt.try_play(usize::MAX, false),
+            Err(MoveError::OutOfRange(usize::MAX))
+        );
+        assert_eq!(ttt.try_play(4, false), Err(MoveError::Occupied(4)));
+        assert_eq!(ttt.try_clear(9), Err(MoveError::OutOfRange(9)));
+        assert_eq!(ttt.try_clear(0), Err(MoveError::Empty(0)));
+        assert_eq!(ttt.board, position(&[4]).board);
+
+        assert_eq!(ttt.try_play(0, false), Ok(()));
+        assert_eq!(ttt.try_clear(0), Ok(()));
+        assert_eq!(ttt.board, position(&[4]).board);
+
+        // The trait's own methods leave the game as it was.
+        let hash = ttt.position_hash();
+        ttt.play(&usize::MAX, true);
+        ttt.clear(&usize::MAX);
+        ttt.play(&ttt.get_a_sentinel_move(), false);
+        assert_eq!(ttt.board, position(&[4]).board);
+        assert_eq!(ttt.move_log(), &[4]);
+        assert_eq!(ttt.position_hash(), hash);
+    }
+
+    #[test]
+    fn aspiration_search_agrees_with_full_window() {
+        let positions: Vec<&[usize]> = vec![&[], &[4], &[0, 4, 8], &[0, 1], &[0, 3, 1, 4]];
+        for moves in positions {
+            for is_maximizing in [true, false] {
+                let expected = position(moves).get_best_move(9, is_maximizing);
+                // Narrow windows fail whenever a win comes into
+                // view, and must widen to find the same move.
+                for window in [0.5, 10., 2000.] {
+                    let mut ttt = position(moves);
+                    let mv = ttt.get_best_move_aspiration(9, is_maximizing, window);
+                    assert_eq!(mv, expected, "{:?} with window {}", moves, window);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_move_reads_both_notations() {
+        let ttt = TicTacToe::new(3);
+        assert_eq!(ttt.parse_move("0,0"), Some(0));
+        assert_eq!(ttt.parse_move(" 1 , 2 "), Some(5));
+        assert_eq!(ttt.parse_move("2,1"), Some(7));
+
+        assert_eq!(ttt.parse_move("a1"), Some(0));
+        assert_eq!(ttt.parse_move("b2"), Some(4));
+        assert_eq!(ttt.parse_move("C2\n"), Some(5));
+        assert_eq!(ttt.parse_move("b3"), Some(7));
+
+        for off_board in ["3,0", "0,3", "d1", "a4", "a0", "aa1"] {
+            assert_eq!(ttt.parse_move(off_board), None, "{}", off_board);
+        }
+        for garbage in ["", "b", "2", "1,", ",1", "-1,0", "b2x", "1;2"] {
+            assert_eq!(ttt.parse_move(garbage), None, "{}", garbage);
+        }
+    }
+
+    #[test]
+    fn open_lines_evaluator_prefers_the_center() {
+        let mut ttt = TicTacToe::new(5).with_evaluator(TicTacToe::open_lines);
+        assert_eq!(ttt.evaluate(), 0.);
+
+        // The center lies on a row, a column, and both diagonals.
+        ttt.play(&12, true);
+        assert_eq!(ttt.evaluate(), 4.);
+        ttt.clear(&12);
+
+        assert_eq!(ttt.get_best_move(2, false), 12);
+
+        // Without it every move looks the same.
+        let mut plain = TicTacToe::new(5);
+        plain.play(&12, true);
+        assert_eq!(plain.evaluate(), 0.);
+    }
+
+    #[test]
+    fn score_move_ranks_the_center_above_corners_and_edges() {
+        let ttt = TicTacToe::new(3);
+        assert!(ttt.score_move(&4) > ttt.score_move(&0));
+        assert!(ttt.score_move(&0) > ttt.score_move(&1));
+        assert_eq!(ttt.order_moves(vec![1, 0, 4], true), vec![4, 0, 1]);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn the_empty_board_solves_to_a_draw() {
+        let ttt = TicTacToe::new(3);
+        let solution = ttt.solve_all();
+        assert_eq!(solution[&ttt.position_hash()], 0.);
+        // Every legal position, including finished ones.
+        assert_eq!(solution.len(), 5478);
+
+        // Blocking the top row also threatens two lines at once.
+        let ttt = position(&[4, 0, 8, 1]);
+        assert_eq!(ttt.best_move_from_solution(&solution), Some(2));
+        assert_eq!(solution[&ttt.position_hash()], TicTacToe::WIN_SCORE);
+    }
+
+    #[test]
+    fn canonical_moves_collapse_symmetric_ones() {
+        // A corner, an edge, and the center.
+        assert_eq!(TicTacToe::new(3).canonical_moves(), vec![0, 1, 4]);
+
+        // Only the diagonal through 0 and 4 is a mirror now.
+        assert_eq!(position(&[4, 0]).canonical_moves(), vec![1, 2, 5, 8]);
+
+        // Nothing is symmetric any more.
+        let ttt = position(&[4, 0, 1]);
+        assert_eq!(ttt.canonical_moves(), ttt.get_available_moves());
+
+        // A 2x3 board has no diagonal mirrors.
+        assert_eq!(
+            TicTacToe::with_dimensions(2, 3).canonical_moves(),
+            vec![0, 1]
+        );
+    }
+
+    #[test]
+    fn symmetric_root_searches_fewer_nodes_for_the_same_score() {
+        use crate::strategy::counting::CountingGame;
+
+        let mut full = CountingGame::new(TicTacToe::new(3));
+        let (_, full_score) = full.get_best_move_with_score(9, false);
+
+        let mut symmetric = CountingGame::new(TicTacToe::new(3).with_symmetric_root(true));
+        let (best_move, symmetric_score) = symmetric.get_best_move_with_score(9, false);
+
+        assert!([0, 1, 4].contains(&best_move));
+        assert_eq!(symmetric_score, full_score);
+        assert!(symmetric.nodes < full.nodes);
+    }
+
+    #[test]
+    fn clearing_a_move_restores_the_hash() {
+        let mut ttt = position(&[4, 0]);
+        let original = ttt.position_hash();
+
+        ttt.play(&8, true);
+        assert_ne!(ttt.position_hash(), original);
+        ttt.clear(&8);
+        assert_eq!(ttt.position_hash(), original);
+
+        // The same position reached in another order hashes the same,
+        // as does the same board parsed from text.
+        assert_eq!(position(&[4, 0, 8, 2]).position_hash(), {
+            let mut ttt = position(&[8, 2]);
+            ttt.play(&4, true);
+            ttt.play(&0, false);
+            ttt.position_hash()
+        });
+        let parsed = TicTacToe::from_str_board("x--\n-o-\n---").unwrap();
+        assert_eq!(parsed.position_hash(), original);
+    }
+
+    #[test]
+    fn stats_count_a_two_move_endgame() {
+        use crate::strategy::alpha_beta_minimax::SearchStats;
+        use crate::strategy::counting::CountingGame;
+
+        // o to move with 6 and 8 left: 6 wins outright, and
+        // 8 leaves x a single reply that fills the board.
+        let mut ttt = TicTacToe::from_str_board("oxo\noxx\n-o-").unwrap();
+        let (best_move, stats) = ttt.get_best_move_with_stats(9, false);
+        assert_eq!(best_move, 6);
+        assert_eq!(
+            stats,
+            SearchStats {
+                nodes_visited: 3,
+                cutoffs: 0,
+                max_depth_reached: 2,
+            }
+        );
+
+        let mut ttt = CountingGame::new(position(&[4, 0]));
+        let (_, stats) = ttt.get_best_move_with_stats(9, true);
+        assert_eq!(stats.nodes_visited, ttt.nodes);
+        assert!(stats.cutoffs > 0);
+        assert_eq!(stats.max_depth_reached, 7);
+    }
+
+    #[test]
+    fn killer_moves_reduce_nodes() {
+        use crate::strategy::alpha_beta_minimax::{SearchContext, INF, NEG_INF};
+        use crate::strategy::counting::CountingGame;
+
+        let mut ttt = TicTacToe::new(4);
+        ttt.play(&5, true);
+        ttt.play(&0, false);
+
+        let mut without_killers = CountingGame::new(ttt.clone());
+        let plain = without_killers.minimax_score_with_context(
+            6,
+            false,
+            NEG_INF,
+            INF,
+            6,
+            &mut SearchContext::new().without_killer_moves(),
+        );
+
+        let mut with_killers = CountingGame::new(ttt);
+        let mut context = SearchContext::new();
+        let killed =
+            with_killers.minimax_score_with_context(6, false, NEG_INF, INF, 6, &mut context);
+
+        assert_eq!(killed, plain);
+        assert!(context.killer_moves.iter().any(|k| k[0].is_some()));
+        assert!(with_killers.nodes < without_killers.nodes);
+    }
+
+    #[test]
+    fn history_heuristic_reduces_nodes() {
+        use crate::strategy::alpha_beta_minimax::{SearchContext, INF, NEG_INF};
+        use crate::strategy::counting::CountingGame;
+
+        let mut ttt = TicTacToe::new(4);
+        ttt.play(&5, true);
+        ttt.play(&0, false);
+
+        let mut without_history = CountingGame::new(ttt.clone());
+        let plain = without_history.minimax_score_with_context(
+            6,
+            false,
+            NEG_INF,
+            INF,
+            6,
+            &mut SearchContext::new(),
+        );
+
+        let mut with_history = CountingGame::new(ttt);
+        let mut context = SearchContext::new().with_history();
+        let ordered =
+            with_history.minimax_score_with_context(6, false, NEG_INF, INF, 6, &mut context);
+
+        assert_eq!(ordered, plain);
+        assert!(!context.history.is_empty());
+        assert!(with_history.nodes < without_history.nodes);
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_search_agrees_with_sequential() {
+        let mut ttt = TicTacToe::new(3);
+        ttt.play(&4, true);
+        ttt.play(&0, false);
+
+        let expected = ttt.get_best_move(9, true);
+        assert_eq!(ttt.get_best_move_parallel(9, true), expected);
+    }
+
+    #[test]
+    fn evaluating_for_the_minimizer_negates_the_score() {
+        let mut ttt = TicTacToe::new(3).with_evaluator(TicTacToe::open_lines);
+        for (mv, maximizer) in [(4, true), (0, false), (8, true)] {
+            ttt.play(&mv, maximizer);
+            assert_ne!(ttt.evaluate_for(true), 0.);
+            assert_eq!(ttt.evaluate_for(false), -ttt.evaluate_for(true));
+            assert_eq!(ttt.evaluate_for(true), ttt.evaluate());
+        }
+
+        for mv in [2, 6] {
+            ttt.play(&mv, true);
+        }
+        assert_eq!(ttt.evaluate_for(false), TicTacToe::LOSS_SCORE);
+    }
+
+    #[test]
+    fn rollouts_favour_a_winning_position() {
+        use crate::strategy::alpha_beta_minimax::{SearchConfig, SearchContext};
+
+        // The maximizer threatens both 3 and 8, and the
+        // minimizer, to move, can only block one of them.
+        let mut ttt = TicTacToe::new(3);
+        for (mv, maximizer) in [(0, true), (1, false), (4, true), (2, false), (6, true)] {
+            ttt.play(&mv, maximizer);
+        }
+        let before = ttt.clone();
+        let mut rng = 7u64;
+        let average = ttt.rollout_evaluate(200, &mut rng);
+        assert!(average > 0.5 * TicTacToe::WIN_SCORE);
+
+        // The minimizer blocks one threat, and the search scores
+        // the leaves by playouts rather than as level.
+        let config = SearchConfig::default().with_rollouts(50, 7);
+        let mut search = SearchContext::new().with_config(config);
+        let best_move = ttt.get_best_move_with_context(0, true, &mut search);
+        assert!([3, 8].contains(&best_move));
+        assert_ne!(search.rng, 7);
+        assert_eq!(ttt, before);
+        assert_eq!(ttt.position_hash(), before.position_hash());
+    }
+
+    #[test]
+    fn undo_move_takes_back_the_last_move() {
+        let mut ttt = TicTacToe::new(3);
+        assert_eq!(ttt.undo_move(), Err(UndoError::NothingToUndo));
+
+        ttt.play(&4, true);
+        ttt.play(&0, false);
+        let hash = ttt.position_hash();
+        ttt.play(&8, true);
+        assert_eq!(ttt.undo_move(), Ok(()));
+        assert_eq!(ttt.board[8], '-');
+        assert_eq!(ttt.position_hash(), hash);
+
+        assert_eq!(ttt.undo_move(), Ok(()));
+        assert_eq!(ttt.undo_move(), Ok(()));
+        assert_eq!(ttt, TicTacToe::new(3));
+    }
+
+    #[test]
+    fn forced_wins_decode_to_their_distance() {
+        use crate::strategy::alpha_beta_minimax::mate_in;
+
+        // The maximizer completes the top row at once.
+        let mut ttt = TicTacToe::new(3);
+        for (mv, maximizer) in [(0, true), (3, false), (1, true), (4, false)] {
+            ttt.play(&mv, maximizer);
+        }
+        let (best_move, score, distance) = ttt.get_best_move_full(9, false);
+        assert_eq!(best_move, 2);
+        assert_eq!(distance, Some(1));
+        assert_eq!(mate_in(score, TicTacToe::WIN_SCORE), Some(1));
+
+        // The maximizer forks two lines, only one of which
+        // the minimizer can block.
+        let mut ttt = TicTacToe::new(3);
+        for (mv, maximizer) in [(0, true), (8, false), (4, true), (1, false)] {
+            ttt.play(&mv, maximizer);
+        }
+        let (_, _, distance) = ttt.get_best_move_full(9, false);
+        assert_eq!(distance, Some(3));
+
+        // The same position, with the minimizer to move, is drawn.
+        let (_, score, distance) = ttt.get_best_move_full(9, true);
+        assert_eq!((score, distance), (0., None));
+        assert_eq!(
+            mate_in(-TicTacToe::WIN_SCORE * 0.998, TicTacToe::WIN_SCORE),
+            Some(-2)
+        );
+    }
+
+    #[test]
+    fn batch_evaluation_matches_one_at_a_time() {
+        let open = TicTacToe::new(3).with_evaluator(TicTacToe::open_lines);
+        let mut positions = vec![open.clone()];
+        for (idx, mv) in [4, 0, 2, 6, 3, 5, 1, 7].iter().enumerate() {
+            let last = positions.last().unwrap();
+            positions.push(last.next_state(mv, idx % 2 == 0));
+        }
+        let mut won = open;
+        for mv in [0, 1, 2] {
+            won.play(&mv, true);
+        }
+        positions.push(won);
+
+        let refs: Vec<&TicTacToe> = positions.iter().collect();
+        let one_at_a_time: Vec<f64> = positions.iter().map(|game| game.evaluate()).collect();
+        assert_eq!(TicTacToe::evaluate_batch(&refs), one_at_a_time);
+    }
+
+    #[test]
+    fn batched_search_agrees_with_alpha_beta() {
+        let mut ttt = TicTacToe::new(3);
+        ttt.play(&4, true);
+        ttt.play(&0, false);
+        for depth in [1, 3, 9] {
+            assert_eq!(
+                ttt.get_best_move_batched(depth, true),
+                ttt.get_best_move(depth, true)
+            );
+        }
+    }
+
+    #[test]
+    fn timed_search_returns_a_legal_move() {
+        let mut ttt = TicTacToe::new(3);
+        ttt.play(&4, true);
+
+        let mv = ttt.get_best_move_timed(std::time::Duration::from_secs(1), true);
+        assert!(ttt.is_a_valid_move(&mv));
+    }
+
+    #[test]
+    fn principal_variation_starts_with_best_move_and_is_legal() {
+        let mut ttt = TicTacToe::new(3);
+        ttt.play(&4, true);
+        let before = ttt.clone();
+
+        let variation = ttt.get_principal_variation(8, true);
+        assert_eq!(ttt, before);
+        assert_eq!(variation[0], ttt.get_best_move(8, true));
+
+        let mut is_maximizing = false;
+        for mv in variation.iter() {
+            assert!(ttt.is_a_valid_move(mv));
+            ttt.play(mv, is_maximizing);
+            is_maximizing = !is_maximizing;
+        }
+        assert!(ttt.is_game_complete());
+    }
+
+    /// Set up a 3x3 board from alternating moves,
+    /// with the maximizer moving first.
+    fn position(moves: &[usize]) -> TicTacToe {
+        let mut ttt = TicTacToe::new(3);
+        for (idx, mv) in moves.iter().enumerate() {
+            ttt.play(mv, idx % 2 == 0);
+        }
+        ttt
+    }
+
+    #[test]
+    fn ply_counts_the_moves_played_and_taken_back() {
+        let mut ttt = TicTacToe::new(3);
+        assert_eq!(ttt.ply(), 0);
+        ttt.play(&4, true);
+        assert_eq!(ttt.ply(), 1);
+        ttt.play(&0, false);
+        assert_eq!(ttt.ply(), 2);
+        ttt.clear(&0);
+        assert_eq!(ttt.ply(), 1);
+        ttt.undo_move().unwrap();
+        assert_eq!(ttt.ply(), 0);
+    }
+
+    #[test]
+    fn next_state_leaves_the_original_untouched() {
+        let ttt = position(&[4]);
+        let next = ttt.next_state(&0, false);
+
+        assert_eq!(ttt.board, position(&[4]).board);
+        assert_eq!(next.board, position(&[4, 0]).board);
+        assert_eq!(next.board[0], 'x');
+    }
+
+    #[test]
+    fn integer_scores_play_identically() {
+        use crate::strategy::scored::ScoredMiniMaxStrategy;
+
+        let positions: Vec<&[usize]> = vec![&[], &[4], &[0, 4, 8], &[0, 1], &[0, 3, 1, 4]];
+        for moves in positions {
+            for is_maximizing in [true, false] {
+                let mut ttt = position(moves);
+                let (float_move, float_score) = ttt.get_best_move_with_score(9, is_maximizing);
+                let before = ttt.clone();
+                let (int_move, int_score) = ttt.get_best_move_scored(9, is_maximizing);
+                assert_eq!(ttt, before);
+                assert_eq!(int_move, float_move, "{:?}", moves);
+                assert_eq!(int_score as f64, float_score, "{:?}", moves);
+            }
+        }
+    }
+
+    #[test]
+    fn best_moves_all_share_the_top_score() {
+        use crate::strategy::alpha_beta_minimax::SCORE_EPSILON;
+
+        // Every first move draws with best play.
+        let mut ttt = TicTacToe::new(3);
+        let mut best_moves = ttt.get_best_moves(9, false);
+        best_moves.sort_unstable();
+        assert_eq!(best_moves, (0..9).collect::<Vec<_>>());
+
+        // x must block the middle column at 7.
+        let mut ttt = position(&[4, 0, 1]);
+        let ranked = ttt.rank_moves(9, true);
+        let best_moves = ttt.get_best_moves(9, true);
+        assert_eq!(best_moves, vec![7]);
+        for mv in &best_moves {
+            let &(_, score) = ranked
+                .iter()
+                .find(|(ranked_mv, _)| ranked_mv == mv)
+                .unwrap();
+            assert!((score - ranked[0].1).abs() <= SCORE_EPSILON);
+        }
+        assert!(ranked[best_moves.len()..]
+            .iter()
+            .all(|&(_, score)| (score - ranked[0].1).abs() > SCORE_EPSILON));
+    }
+
+    #[test]
+    fn rank_moves_puts_the_only_winning_move_first() {
+        // o: 0, 1 and x: 3, 4, so o must complete the top row.
+        let mut ttt = position(&[0, 3, 1, 4]);
+        let ranked = ttt.rank_moves(9, false);
+
+        assert_eq!(ranked.len(), 5);
+        assert_eq!(ranked[0], (2, 999.));
+        assert!(ranked[1..].iter().all(|&(_, score)| score <= 0.));
+        assert!(ranked.windows(2).all(|pair| pair[0].1 >= pair[1].1));
+        assert_eq!(ttt.board, position(&[0, 3, 1, 4]).board);
+    }
+
+    #[test]
+    fn pinned_best_moves_on_known_positions() {
+        let cases: Vec<(&[usize], bool, usize)> = vec![
+            (&[], true, 7),
+            (&[], false, 7),
+            (&[4], true, 8),
+            (&[0, 4, 8], true, 7),
+            (&[0, 1], false, 3),
+            (&[4, 0, 8], false, 7),
+            (&[0, 3, 1, 4], false, 2),
+            (&[2, 4, 6], true, 7),
+        ];
+        for (moves, is_maximizing, expected) in cases {
+            let mut ttt = position(moves);
+            assert_eq!(ttt.get_best_move(9, is_maximizing), expected);
+        }
+    }
+
+    #[test]
+    fn prefers_mate_in_1_over_mate_in_3() {
+        // 'o' can win on the spot at 8, or set up a
+        // fork at 2 that only wins two plies later.
+        let mut ttt = position(&[0, 3, 4, 5]);
+        let (best_move, score) = ttt.get_best_move_with_score(9, false);
+        assert_eq!(best_move, 8);
+        assert_eq!(score, 999.);
+
+        // Here a fork is the quickest win available.
+        let mut ttt = position(&[4, 8, 0, 1]);
+        let (_, score) = ttt.get_best_move_with_score(9, false);
+        assert_eq!(score, 997.);
+    }
+
+    #[test]
+    fn five_by_five_wins_with_a_run_of_four() {
+        let mut ttt = TicTacToe::new(5).with_win_length(4);
+        for col in 1..4 {
+            ttt.play(&(5 * 2 + col), true);
+        }
+        assert!(!ttt.is_game_complete());
+
+        ttt.play(&(5 * 2 + 4), true);
+        assert_eq!(ttt.check_rows(), 'o');
+        assert!(ttt.is_game_complete());
+    }
+
+    #[test]
+    fn five_by_five_wins_off_the_main_diagonals() {
+        let mut ttt = TicTacToe::new(5).with_win_length(4);
+        for idx in [1, 7, 13, 19].iter() {
+            ttt.play(idx, false);
+        }
+        assert_eq!(ttt.check_diagonals(), 'x');
+
+        let mut ttt = TicTacToe::new(5).with_win_length(4);
+        for idx in [9, 13, 17, 21].iter() {
+            ttt.play(idx, true);
+        }
+        assert_eq!(ttt.check_diagonals(), 'o');
+    }
+
+    #[test]
+    fn rectangular_board_indexing() {
+        let ttt = TicTacToe::with_dimensions(2, 3);
+        assert_eq!(ttt.board.len(), 6);
+        assert_eq!(ttt.get_available_moves().len(), 6);
+        assert_eq!(ttt.to_coordinates(4), (1, 1));
+        assert_eq!(ttt.to_coordinates(5), (1, 2));
+        assert_eq!(ttt.to_index(1, 0), 3);
+        assert_eq!(ttt.to_string(), "---\n---\n");
+    }
+
+    #[test]
+    fn rectangular_board_wins() {
+        let mut ttt = TicTacToe::with_dimensions(2, 3).with_win_length(3);
+        for idx in 3..6 {
+            ttt.play(&idx, true);
+        }
+        assert_eq!(ttt.check_rows(), 'o');
+
+        let mut ttt = TicTacToe::with_dimensions(3, 2);
+        ttt.play(&1, false);
+        ttt.play(&2, false);
+        assert_eq!(ttt.check_cols(), '-');
+        assert_eq!(ttt.check_diagonals(), 'x');
+    }
+
+    #[test]
+    fn from_str_board_round_trips() {
+        let ttt = position(&[4, 0, 8, 2]);
+        let parsed = TicTacToe::from_str_board(&ttt.to_string()).unwrap();
+        assert_eq!(parsed.board, ttt.board);
+        assert_eq!(parsed.size, 3);
+
+        let parsed = TicTacToe::from_str_board("xo-\n-x-\noox").unwrap();
+        assert_eq!(parsed.to_string(), "xo-\n-x-\noox\n");
+    }
+
+    #[test]
+    fn parse_reads_rows_separated_by_slashes_or_newlines() {
+        use core::convert::TryFrom;
+
+        let parsed: TicTacToe = "xo-/-x-/---".parse().unwrap();
+        assert_eq!(parsed.to_string(), "xo-\n-x-\n---\n");
+        assert_eq!(parsed.position_hash(), {
+            let mut ttt = TicTacToe::new(3);
+            ttt.set(0, 0, 'x').unwrap();
+            ttt.set(0, 1, 'o').unwrap();
+            ttt.set(1, 1, 'x').unwrap();
+            ttt.position_hash()
+        });
+
+        let parsed = TicTacToe::try_from("xo-\n-x-\n---").unwrap();
+        assert_eq!(
+            parsed.board,
+            "xo-/-x-/---".parse::<TicTacToe>().unwrap().board
+        );
+    }
+
+    #[test]
+    fn parse_rejects_unknown_symbols_and_non_square_boards() {
+        assert_eq!(
+            "xo-/-a-/---".parse::<TicTacToe>().unwrap_err(),
+            ParseBoardError::UnknownChar('a')
+        );
+        assert_eq!(
+            "xo-/-x-".parse::<TicTacToe>().unwrap_err(),
+            ParseBoardError::NotSquare { rows: 2, cols: 3 }
+        );
+    }
+
+    #[test]
+    fn from_str_board_rejects_bad_boards() {
+        assert_eq!(
+            TicTacToe::from_str_board("xo-\n-x\noox").unwrap_err(),
+            ParseBoardError::RaggedRow {
+                row: 1,
+                expected: 3,
+                found: 2
+            }
+        );
+        assert_eq!(
+            TicTacToe::from_str_board("xo-\n-x-").unwrap_err(),
+            ParseBoardError::NotSquare { rows: 2, cols: 3 }
+        );
+        assert_eq!(
+            TicTacToe::from_str_board("xo-\n-a-\noox").unwrap_err(),
+            ParseBoardError::UnknownChar('a')
+        );
+        assert_eq!(
+            TicTacToe::from_str_board("").unwrap_err(),
+            ParseBoardError::Empty
+        );
+    }
+
+    #[test]
+    fn with_board_accepts_only_reachable_positions() {
+        let board: Vec<char> = "xo--x---o".chars().collect();
+        let ttt = TicTacToe::with_board(board.clone(), 3).unwrap();
+        assert_eq!(ttt.board, board);
+        assert_eq!(ttt.position_hash(), position(&[1, 0, 8, 4]).position_hash());
+
+        assert_eq!(
+            TicTacToe::with_board("xxx------".chars().collect(), 3).unwrap_err(),
+            ParseBoardError::Unreachable {
+                maximizer: 0,
+                minimizer: 3
+            }
+        );
+        assert_eq!(
+            TicTacToe::with_board(board, 4).unwrap_err(),
+            ParseBoardError::WrongLength {
+                expected: 16,
+                found: 9
+            }
+        );
+        assert_eq!(
+            TicTacToe::with_board("xo-a-----".chars().collect(), 3).unwrap_err(),
+            ParseBoardError::UnknownChar('a')
+        );
+    }
+
+    #[test]
+    fn a_board_with_two_winners_is_invalid() {
+        let board: Vec<char> = "xxx---ooo".chars().collect();
+        assert_eq!(
+            TicTacToe::with_board(board, 3).unwrap_err(),
+            ParseBoardError::TwoWinners
+        );
+
+        let ttt = TicTacToe::from_str_board("xxx\nooo\n---").unwrap();
+        assert_eq!(ttt.validate(), Err(ParseBoardError::TwoWinners));
+        assert_eq!(position(&[0, 3, 1, 4, 2]).validate(), Ok(()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        let ttt = position(&[4, 0, 8]).with_win_length(3);
+        let json = serde_json::to_string(&ttt).unwrap();
+        let restored: TicTacToe = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(restored.board, ttt.board);
+        assert_eq!(restored.size, ttt.size);
+        assert_eq!(restored.default_char, ttt.default_char);
+        assert_eq!(restored.maximizer, ttt.maximizer);
+        assert_eq!(restored.minimizer, ttt.minimizer);
+        assert_eq!(restored.win_length, ttt.win_length);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_mismatched_board_length() {
+        let json = r#"{
+            "board": ["-", "-", "-", "-"],
+            "size": 3,
+            "default_char": "-",
+            "maximizer": "o",
+            "minimizer": "x"
+        }"#;
+        assert!(serde_json::from_str::<TicTacToe>(json).is_err());
+
+        let json = json.replace(r#""size": 3"#, r#""size": 2"#);
+        let ttt: TicTacToe = serde_json::from_str(&json).unwrap();
+        assert_eq!((ttt.rows, ttt.cols, ttt.win_length), (2, 2, 2));
+    }
+
+    #[test]
+    fn undo_and_redo() {
+        let mut ttt = position(&[4, 0]);
+        let before = ttt.board.clone();
+
+        ttt.play(&8, true);
+        assert_eq!(ttt.undo_last(), Some(8));
+        assert_eq!(ttt.board, before);
+
+        assert_eq!(ttt.redo(), Some(8));
+        assert_eq!(ttt.board[8], 'o');
+        assert_eq!(ttt.redo(), None);
+
+        assert_eq!(ttt.undo_last(), Some(8));
+        assert_eq!(ttt.undo_last(), Some(0));
+        assert_eq!(ttt.redo(), Some(0));
+        assert_eq!(ttt.board[0], 'x');
+
+        // Playing a new move forgets whatever could be redone.
+        ttt.play(&2, true);
+        assert_eq!(ttt.redo(), None);
+    }
+
+    #[test]
+    fn reset_matches_a_fresh_game() {
+        let mut ttt = position(&[4, 0, 8]);
+        ttt.undo_last();
+        ttt.reset();
+
+        let fresh = TicTacToe::new(3);
+        assert_eq!(ttt.board, fresh.board);
+        assert_eq!(ttt.position_hash(), fresh.position_hash());
+        assert_eq!(ttt.undo_last(), None);
+        assert_eq!(ttt.redo(), None);
+    }
+
+    #[test]
+    fn current_player_follows_the_filled_cells() {
+        assert!(TicTacToe::new(3).current_player());
+        assert!(!position(&[4]).current_player());
+        assert!(position(&[4, 0]).current_player());
+
+        // The minimizer to move can block or lose.
+        let mut ttt = position(&[0, 3, 1]);
+        assert!(!ttt.current_player());
+        assert_eq!(ttt.get_best_move_for_current_player(9), 2);
+    }
+
+    #[test]
+    fn winning_line_finds_the_cells_of_the_win() {
+        let ttt = TicTacToe::from_str_board("ooo\nxx-\n---").unwrap();
+        assert_eq!(ttt.winning_line(), Some(vec![0, 1, 2]));
+
+        let ttt = TicTacToe::from_str_board("xoo\n-x-\n-ox").unwrap();
+        assert_eq!(ttt.winning_line(), Some(vec![0, 4, 8]));
+
+        let mut ttt = TicTacToe::new(5).with_win_length(4);
+        for (mv, maximizer) in [(4, true), (0, false), (8, true), (1, false), (12, true)] {
+            ttt.play(&mv, maximizer);
+        }
+        assert_eq!(ttt.winning_line(), None);
+        ttt.play(&16, true);
+        assert_eq!(ttt.winning_line(), Some(vec![4, 8, 12, 16]));
+    }
+
+    #[test]
+    fn the_minimizer_can_move_first() {
+        let mut ttt = TicTacToe::new(3).with_first_player(false);
+        assert!(!ttt.current_player());
+        ttt.play(&4, false);
+        assert!(ttt.current_player());
+    }
+
+    #[test]
+    fn get_and_set_by_row_and_column() {
+        let ttt = position(&[5, 0]);
+        assert_eq!(ttt.get(1, 2), Some(ttt.board[3 + 2]));
+        assert_eq!(ttt.get(1, 2), Some('o'));
+        assert_eq!(ttt.get(3, 0), None);
+        assert_eq!(ttt.get(0, 3), None);
+
+        let mut ttt = TicTacToe::new(3);
+        ttt.set(1, 2, 'o').unwrap();
+        ttt.set(0, 0, 'x').unwrap();
+        assert_eq!(ttt.board, position(&[5, 0]).board);
+        assert_eq!(ttt.position_hash(), position(&[5, 0]).position_hash());
+        assert_eq!(ttt.set(0, 3, 'o'), Err(MoveError::OutOfBounds(0, 3)));
+    }
+
+    #[test]
+    fn a_loaded_win_is_found_after_more_moves() {
+        let mut ttt = TicTacToe::from_str_board("ooo\nxx-\n---").unwrap();
+        assert_eq!(ttt.get_winner(), Some('o'));
+
+        // The move played isn't on the winning line.
+        ttt.play(&5, false);
+        assert_eq!(ttt.get_winner(), Some('o'));
+
+        let mut ttt = TicTacToe::with_board("xxx-oo-o-".chars().collect(), 3).unwrap();
+        ttt.play(&8, true);
+        assert_eq!(ttt.get_winner(), Some('x'));
+    }
+
+    #[test]
+    fn a_line_completed_with_set_after_a_move_is_a_win() {
+        let mut ttt = position(&[4]);
+        assert_eq!(ttt.get_winner(), None);
+
+        // The top row doesn't pass through the last move played.
+        for col in 0..3 {
+            ttt.set(0, col, 'x').unwrap();
+        }
+        assert_eq!(ttt.get_winner(), Some('x'));
+        assert!(ttt.is_game_complete());
+        assert_eq!(ttt.result(), Some(GameResult::Player2Win));
+    }
+
+    #[test]
+    fn a_custom_empty_symbol_leaves_every_cell_playable() {
+        let mut ttt = TicTacToe::new(3).with_default_char('.');
+        assert_eq!(ttt.legal_move_count(), 9);
+        assert_eq!(ttt.get_available_moves(), (0..9).collect::<Vec<_>>());
+        assert!(!ttt.is_game_complete());
+        assert!(!ttt.is_game_tied());
+
+        ttt.play(&4, true);
+        assert_eq!(ttt.legal_move_count(), 8);
+        assert_eq!(ttt.to_string(), "...\n.o.\n...\n");
+    }
+
+    #[test]
+    fn the_move_log_keeps_the_order_of_play() {
+        let mut ttt = position(&[4, 0, 8]);
+        assert_eq!(ttt.move_log(), &[4, 0, 8]);
+        assert_eq!(ttt.to_transcript(), "1. o b2, 2. x a1, 3. o c3");
+
+        ttt.undo_last();
+        assert_eq!(ttt.move_log(), &[4, 0]);
+        assert_eq!(TicTacToe::new(3).to_transcript(), "");
+    }
+
+    #[test]
+    fn board_dimensions_are_rows_by_columns() {
+        assert_eq!(TicTacToe::default().board_dimensions(), Some((3, 3)));
+        assert_eq!(
+            TicTacToe::with_dimensions(2, 5).board_dimensions(),
+            Some((2, 5))
+        );
+    }
+
+    #[test]
+    fn search_depth_is_bounded_by_the_empty_cells() {
+        let mut ttt = position(&[4, 0, 8, 2, 1, 7]);
+        assert_eq!(ttt.max_depth_hint(), Some(3));
+
+        let (best_move, stats) = ttt.get_best_move_with_stats(50, true);
+        assert_eq!(best_move, ttt.get_best_move(3, true));
+        assert!(stats.max_depth_reached <= 3);
+    }
+
+    #[test]
+    fn legal_move_count_matches_the_available_moves() {
+        let mut boards = vec![
+            TicTacToe::new(3),
+            position(&[4]),
+            position(&[4, 0, 8, 2]),
+            position(&[0, 3, 1, 4, 2]),
+            TicTacToe::with_dimensions(3, 5),
+        ];
+        let mut full = TicTacToe::new(3);
+        for mv in 0..9 {
+            full.play(&mv, mv % 2 == 0);
+        }
+        boards.push(full);
+
+        for ttt in boards {
+            assert_eq!(ttt.legal_move_count(), ttt.get_available_moves().len());
+        }
+    }
+
+    #[test]
+    fn terminal_positions_are_wins_and_full_boards() {
+        assert!(!TicTacToe::new(3).is_terminal());
+        assert!(!position(&[4, 0, 8]).is_terminal());
+
+        let won = position(&[0, 3, 1, 4, 2]);
+        assert_eq!(won.get_winner(), Some('o'));
+        assert!(won.is_terminal());
+
+        let tied = position(&[4, 0, 8, 2, 1, 7, 6, 3, 5]);
+        assert!(tied.is_game_tied());
+        assert!(tied.is_terminal());
+    }
+
+    #[test]
+    fn filling_a_move_list_matches_allocating_one() {
+        let mut moves = vec![42, 7];
+        for ttt in [
+            TicTacToe::new(3),
+            position(&[4, 0, 8]),
+            position(&[0, 3, 1, 4, 2]),
+            TicTacToe::with_dimensions(2, 4),
+        ] {
+            ttt.fill_available_moves(&mut moves);
+            assert_eq!(moves, ttt.get_available_moves());
+        }
+    }
+
+    #[test]
+    fn both_players_may_play_any_empty_cell() {
+        for ttt in [TicTacToe::new(3), position(&[4, 0, 8])] {
+            let empty: Vec<usize> = (0..9).filter(|&idx| ttt.board[idx] == '-').collect();
+            assert_eq!(ttt.moves_for(true), empty);
+            assert_eq!(ttt.moves_for(false), empty);
+        }
+    }
+
+    #[test]
+    fn equal_positions_hash_the_same() {
+        use std::collections::HashSet;
+
+        let mut seen = HashSet::new();
+        let mut ttt = TicTacToe::new(3);
+        ttt.play(&0, true);
+        ttt.play(&4, false);
+        assert!(seen.insert(ttt.clone()));
+
+        // The same position, reached in another order.
+        let mut other = TicTacToe::new(3);
+        other.play(&4, false);
+        other.play(&0, true);
+        assert_eq!(ttt, other);
+        assert!(!seen.insert(other.clone()));
+
+        other.play(&8, true);
+        assert!(seen.insert(other));
+        assert_ne!(TicTacToe::new(3), TicTacToe::new(4));
+    }
 }
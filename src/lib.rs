@@ -2,7 +2,16 @@
 //! Minimax algorithm with Alpha-Beta pruning.
 //! Also, where possible, a parallel processing
 //! implementation is provided.
+//!
+//! Without the default `std` feature, the search and
+//! `TicTacToe` build with `alloc` alone, e.g. for
+//! embedded targets, while the other games, the
+//! drivers and the time-limited search are left out.
+#![cfg_attr(not(feature = "std"), no_std)]
 
+extern crate alloc;
+
+#[cfg(feature = "std")]
 mod drivers;
 /// Contains sruct and necessary implementations
 /// for `TicTacToe`: a popular two-player game
@@ -23,5 +32,8 @@ mod drivers;
 // mod tests;
 pub mod games;
 pub mod strategy;
+#[cfg(any(feature = "wasm", test))]
+pub mod wasm;
 
+#[cfg(feature = "std")]
 pub use drivers::*;
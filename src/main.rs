@@ -1,4 +1,4 @@
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use minimax_alpha_beta::*;
 
 #[derive(Parser, Debug, Clone)]
@@ -14,9 +14,53 @@ pub struct Cli {
     /// The depth of the search.
     #[clap(long, default_value_t = 9)]
     pub depth: i64,
+    /// How well the computer plays: easy, medium, or hard.
+    /// Takes the place of the depth when given.
+    #[clap(long)]
+    pub difficulty: Option<Difficulty>,
+    /// Let the computer make the first move.
+    #[clap(long)]
+    pub computer_first: bool,
+    #[clap(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum Command {
+    /// Print the best move in a position instead of playing.
+    Analyze {
+        /// The board, as rows separated by '/', e.g. "xo-/-x-/---".
+        #[clap(long)]
+        board: String,
+        /// The depth of the search.
+        #[clap(long, default_value_t = 9)]
+        depth: i64,
+        /// Whether it is the maximizer ('o') to move,
+        /// rather than the minimizer ('x').
+        #[clap(long)]
+        maximizing: bool,
+    },
 }
 
 fn main() {
     let cli = Cli::parse();
-    play_tic_tac_toe_against_computer_with_depth(cli.size, cli.depth);
+    if let Some(Command::Analyze {
+        board,
+        depth,
+        maximizing,
+    }) = cli.command
+    {
+        match analyze_tic_tac_toe(&board, depth, maximizing) {
+            Ok(analysis) => println!("{}", analysis),
+            Err(err) => eprintln!("{}", err),
+        }
+        return;
+    }
+    let first_player = !cli.computer_first;
+    match cli.difficulty {
+        Some(difficulty) => {
+            play_tic_tac_toe_against_computer_with_difficulty(cli.size, difficulty, first_player)
+        }
+        None => play_tic_tac_toe_against_computer_with_depth(cli.size, cli.depth, first_player),
+    }
 }
@@ -1,8 +1,22 @@
-use crate::strategy::game_strategy::GameStrategy;
+use crate::strategy::game_strategy::{playout_score, random_move, GameStrategy, MAX_PLAYOUT_PLIES};
+use crate::strategy::scored::{OrderedF64, Score};
+use crate::strategy::transposition::{NodeType, TranspositionEntry, TranspositionTable};
+use crate::strategy::zobrist::splitmix64;
+use alloc::{boxed::Box, vec, vec::Vec};
+use core::hash::Hash;
+#[cfg(feature = "parallel")]
+use rayon::prelude::*;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+#[cfg(feature = "std")]
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 
 pub const INF: f64 = f64::INFINITY;
 pub const NEG_INF: f64 = f64::NEG_INFINITY;
 
+/// How close two scores must be to count as tied.
+pub const SCORE_EPSILON: f64 = 1e-9;
+
 /// The behaviour required of any
 /// minimax game engine.
 pub trait AlphaBetaMiniMaxStrategy: GameStrategy {
@@ -15,6 +29,233 @@ pub trait AlphaBetaMiniMaxStrategy: GameStrategy {
         is_maximizing: bool,
     ) -> <Self as GameStrategy>::Move;
 
+    /// The ability to get the best move
+    /// for whichever player the game
+    /// says is to move.
+    fn get_best_move_for_current_player(&mut self, max_depth: i64) -> <Self as GameStrategy>::Move;
+
+    /// The ability to get the best move
+    /// in the current state and for the
+    /// current player, along with the
+    /// minimax score the engine assigns to it.
+    fn get_best_move_with_score(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> (<Self as GameStrategy>::Move, f64);
+
+    /// The ability to get the best move
+    /// in the current state and for the
+    /// current player, along with its score
+    /// and, if the score is a forced result,
+    /// how many plies away it is (see `mate_in`).
+    fn get_best_move_full(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> (<Self as GameStrategy>::Move, f64, Option<i64>);
+
+    /// The ability to get the best move
+    /// in the current state and for the
+    /// current player, searching as the
+    /// given configuration says.
+    fn get_best_move_with_config(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        config: SearchConfig,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to get the best move
+    /// in the current state and for the
+    /// current player, searching with the
+    /// given search context.
+    fn get_best_move_with_context(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        context: &mut SearchContext<<Self as GameStrategy>::Move>,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to get the best move
+    /// in the current state and for the
+    /// current player, along with how much
+    /// work it took to find it.
+    fn get_best_move_with_stats(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> (<Self as GameStrategy>::Move, SearchStats);
+
+    /// The ability to get the best move
+    /// in the current state and for the
+    /// current player, reporting how far
+    /// along the search is to `on_progress`
+    /// each time a root move is scored.
+    fn get_best_move_with_progress<F>(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        on_progress: F,
+    ) -> <Self as GameStrategy>::Move
+    where
+        F: FnMut(SearchProgress<<Self as GameStrategy>::Move>);
+
+    /// The ability to get the best move
+    /// in the current state and for the
+    /// current player, searching the root
+    /// within the window from `alpha` to `beta`
+    /// in the maximizer's terms. A move is always
+    /// returned, but it can only be trusted to be
+    /// the best if its score falls inside the window.
+    fn get_best_move_windowed(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        alpha: f64,
+        beta: f64,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to score every move available
+    /// in the current state for the current player,
+    /// ranked from best to worst for that player.
+    /// Scores are from the maximizer's point of view,
+    /// as with `get_best_move_with_score`.
+    fn rank_moves(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> Vec<(<Self as GameStrategy>::Move, f64)>;
+
+    /// The ability to get every move tied
+    /// for the best score in the current state
+    /// for the current player, rather than
+    /// just the last one scanned.
+    fn get_best_moves(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> Vec<<Self as GameStrategy>::Move>;
+
+    /// The ability to get the best move,
+    /// choosing among the moves tied for
+    /// the best score with the given policy.
+    fn get_best_move_with_tiebreak(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        tiebreak: &TieBreak<<Self as GameStrategy>::Move>,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to play as well as `skill` says,
+    /// from 0 for a move picked uniformly at random to
+    /// 1 for the best move. In between, moves are drawn
+    /// from `rng` with weights that grow exponentially
+    /// with their score, more steeply the higher the skill.
+    #[cfg(feature = "std")]
+    fn get_move_with_skill(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        skill: f64,
+        rng: &mut u64,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to get the best move with
+    /// principal-variation search, which assumes
+    /// the first move ordered at each position is
+    /// the best and only proves the others worse,
+    /// searching one again in full if it isn't.
+    fn get_best_move_pvs(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to get the best move
+    /// while remembering searched positions
+    /// in the given transposition table, so that
+    /// positions reached by different move orders
+    /// are only expanded once.
+    fn get_best_move_with_table(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        table: &mut TranspositionTable,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to get the best move
+    /// by searching every root move on its own
+    /// copy of the game, in parallel.
+    #[cfg(feature = "parallel")]
+    fn get_best_move_parallel(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move
+    where
+        Self: Clone + Send,
+        <Self as GameStrategy>::Move: Send;
+
+    /// The ability to get the best move
+    /// by searching progressively deeper,
+    /// from a depth of 1 up to `max_depth`,
+    /// keeping the move found by the deepest
+    /// completed search. Each search tries the
+    /// best move of the one before it first, and
+    /// keeps the killer moves it recorded.
+    fn get_best_move_iterative(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to get the best move
+    /// by searching progressively deeper,
+    /// looking only within `window` of the
+    /// previous depth's score at first, and
+    /// searching again without limits whenever
+    /// the score falls outside of it.
+    fn get_best_move_aspiration(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        window: f64,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to get the best move
+    /// by searching progressively deeper
+    /// until the time budget runs out, keeping
+    /// the move found by the deepest search
+    /// that completed in time.
+    #[cfg(feature = "std")]
+    fn get_best_move_timed(
+        &mut self,
+        budget: Duration,
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move;
+
+    /// The ability to get the best move by expanding every
+    /// line to `max_depth` without pruning, gathering all the
+    /// leaves first and scoring them in one `evaluate_batch`.
+    fn get_best_move_batched(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move
+    where
+        Self: Clone;
+
+    /// The ability to produce the line of play
+    /// the engine expects from the current state,
+    /// starting with the best move for the current
+    /// player and alternating between the two sides.
+    fn get_principal_variation(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> Vec<<Self as GameStrategy>::Move>;
+
     /// The ability to produce a best (good enough, sometimes)
     /// evaluation score possible over all
     /// possible moves at the current game state.
@@ -26,6 +267,38 @@ pub trait AlphaBetaMiniMaxStrategy: GameStrategy {
         beta: f64,
         max_depth: i64,
     ) -> f64;
+
+    /// The same as `minimax_score` but probes the
+    /// transposition table before expanding a position
+    /// and stores the result after.
+    fn minimax_score_with_table(
+        &mut self,
+        depth: i64,
+        is_maximizing: bool,
+        alpha: f64,
+        beta: f64,
+        max_depth: i64,
+        table: &mut TranspositionTable,
+    ) -> f64;
+
+    /// The same as `minimax_score` but carries the given
+    /// search context along, e.g. to keep its killer moves
+    /// between searches or to inspect them afterwards.
+    fn minimax_score_with_context(
+        &mut self,
+        depth: i64,
+        is_maximizing: bool,
+        alpha: f64,
+        beta: f64,
+        max_depth: i64,
+        context: &mut SearchContext<<Self as GameStrategy>::Move>,
+    ) -> f64;
+
+    /// The ability to score the current state
+    /// by searching only noisy moves, e.g. captures,
+    /// until the position is quiet, so that the score
+    /// isn't taken in the middle of an exchange.
+    fn quiescence(&mut self, alpha: f64, beta: f64, is_maximizing: bool) -> f64;
 }
 
 /// Endow upon anything the ability to
@@ -38,95 +311,1990 @@ impl<T: GameStrategy> AlphaBetaMiniMaxStrategy for T {
         max_depth: i64,
         is_maximizing: bool,
     ) -> <Self as GameStrategy>::Move {
-        let mut best_move: <Self as GameStrategy>::Move = self.get_a_sentinel_move();
+        if let Some(mv) = forced_move(self) {
+            return mv;
+        }
+        self.get_best_move_with_score(max_depth, is_maximizing).0
+    }
+
+    fn get_best_move_for_current_player(&mut self, max_depth: i64) -> <Self as GameStrategy>::Move {
+        // The player to move is the opposite of the one named.
+        let is_maximizing = !self.current_player();
+        self.get_best_move(max_depth, is_maximizing)
+    }
+
+    fn get_best_move_with_score(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> (<Self as GameStrategy>::Move, f64) {
+        search_root(
+            self,
+            max_depth,
+            is_maximizing,
+            &mut SearchContext::default(),
+        )
+    }
+
+    fn get_best_move_full(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> (<Self as GameStrategy>::Move, f64, Option<i64>) {
+        let (best_move, score) = self.get_best_move_with_score(max_depth, is_maximizing);
+        (best_move, score, mate_in(score, Self::WIN_SCORE))
+    }
+
+    fn get_best_move_with_config(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        config: SearchConfig,
+    ) -> <Self as GameStrategy>::Move {
+        let mut search = SearchContext::new().with_config(config);
+        search_root(self, max_depth, is_maximizing, &mut search).0
+    }
+
+    fn get_best_move_with_context(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        context: &mut SearchContext<<Self as GameStrategy>::Move>,
+    ) -> <Self as GameStrategy>::Move {
+        search_root(self, max_depth, is_maximizing, context).0
+    }
+
+    fn get_best_move_with_stats(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> (<Self as GameStrategy>::Move, SearchStats) {
+        let mut search = SearchContext::default();
+        if let Some(mv) = forced_move(self) {
+            return (mv, search.stats);
+        }
+        let (best_move, _) = search_root(self, max_depth, is_maximizing, &mut search);
+        (best_move, search.stats)
+    }
+
+    fn get_best_move_with_progress<F>(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        mut on_progress: F,
+    ) -> <Self as GameStrategy>::Move
+    where
+        F: FnMut(SearchProgress<<Self as GameStrategy>::Move>),
+    {
+        let mut search = SearchContext::new().with_progress(&mut on_progress);
+        search_root(self, max_depth, is_maximizing, &mut search).0
+    }
+
+    fn get_best_move_windowed(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        alpha: f64,
+        beta: f64,
+    ) -> <Self as GameStrategy>::Move {
+        search_root_within(
+            self,
+            max_depth,
+            is_maximizing,
+            alpha,
+            beta,
+            &mut SearchContext::default(),
+        )
+        .0
+    }
 
+    fn rank_moves(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> Vec<(<Self as GameStrategy>::Move, f64)> {
         if self.is_game_complete() {
-            return best_move;
+            return vec![];
         }
 
-        let alpha = NEG_INF;
-        let beta = INF;
+        let mover = !is_maximizing;
+        let mut search = SearchContext::default();
+        let mut ranked: Vec<(<Self as GameStrategy>::Move, f64)> = vec![];
 
-        if is_maximizing {
-            let mut best_move_val: f64 = INF;
+        for mv in self.order_moves(self.get_available_moves(), mover) {
+            let value = score_move::<_, Plain>(
+                self,
+                &mv,
+                max_depth,
+                mover,
+                -OrderedF64::MAX,
+                OrderedF64::MAX,
+                max_depth,
+                &mut search,
+            )
+            .0;
+            ranked.push((mv, if mover { value } else { -value }));
+        }
 
-            for mv in self.get_available_moves() {
-                self.play(&mv, !is_maximizing);
-                let value = self.minimax_score(max_depth, is_maximizing, alpha, beta, max_depth);
-                self.clear(&mv);
-                if value <= best_move_val {
-                    best_move_val = value;
-                    best_move = mv;
-                }
+        if mover {
+            ranked.sort_by(|a, b| b.1.total_cmp(&a.1));
+        } else {
+            ranked.sort_by(|a, b| a.1.total_cmp(&b.1));
+        }
+        ranked
+    }
+
+    fn get_best_moves(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> Vec<<Self as GameStrategy>::Move> {
+        let ranked = self.rank_moves(max_depth, is_maximizing);
+        let best_score = match ranked.first() {
+            Some(&(_, score)) => score,
+            None => return vec![],
+        };
+
+        ranked
+            .into_iter()
+            .take_while(|&(_, score)| (score - best_score).abs() <= SCORE_EPSILON)
+            .map(|(mv, _)| mv)
+            .collect()
+    }
+
+    fn get_best_move_with_tiebreak(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        tiebreak: &TieBreak<<Self as GameStrategy>::Move>,
+    ) -> <Self as GameStrategy>::Move {
+        let best_moves = self.get_best_moves(max_depth, is_maximizing);
+        if best_moves.is_empty() {
+            return self.get_a_sentinel_move();
+        }
+        tiebreak.choose(&best_moves)
+    }
+
+    #[cfg(feature = "std")]
+    fn get_move_with_skill(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        skill: f64,
+        rng: &mut u64,
+    ) -> <Self as GameStrategy>::Move {
+        if skill >= 1. {
+            return self.get_best_move(max_depth, is_maximizing);
+        }
+        if skill <= 0. {
+            let moves = self.get_available_moves();
+            if moves.is_empty() {
+                return self.get_a_sentinel_move();
             }
+            return moves[(splitmix64(rng) % moves.len() as u64) as usize].clone();
+        }
 
-            best_move
-        } else {
-            let mut best_move_val: f64 = NEG_INF;
-
-            for mv in self.get_available_moves() {
-                self.play(&mv, !is_maximizing);
-                let value = self.minimax_score(max_depth, is_maximizing, alpha, beta, max_depth);
-                self.clear(&mv);
-                if value >= best_move_val {
-                    best_move_val = value;
-                    best_move = mv;
-                }
+        // Scores are taken from the mover's point of view and
+        // spread over [0, 1], so that the weights don't depend
+        // on the game's scale.
+        let mover = !is_maximizing;
+        let ranked = self.rank_moves(max_depth, is_maximizing);
+        let values: Vec<f64> = ranked
+            .iter()
+            .map(|&(_, score)| if mover { score } else { -score })
+            .collect();
+        let (best, worst) = match (values.first(), values.last()) {
+            (Some(&best), Some(&worst)) => (best, worst),
+            _ => return self.get_a_sentinel_move(),
+        };
+        let spread = if best > worst { best - worst } else { 1. };
+        let steepness = SKILL_STEEPNESS * skill / (1. - skill);
+        let weights: Vec<f64> = values
+            .iter()
+            .map(|value| (steepness * (value - best) / spread).exp())
+            .collect();
+
+        let mut pick = splitmix64(rng) as f64 / u64::MAX as f64 * weights.iter().sum::<f64>();
+        for ((mv, _), weight) in ranked.iter().zip(&weights) {
+            if pick < *weight {
+                return mv.clone();
             }
-            best_move
+            pick -= weight;
         }
+        ranked[0].0.clone()
     }
 
-    fn minimax_score(
+    fn get_best_move_pvs(
         &mut self,
-        depth: i64,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move {
+        search_root(
+            self,
+            max_depth,
+            is_maximizing,
+            &mut SearchContext::new().with_pvs(),
+        )
+        .0
+    }
+
+    fn get_best_move_with_table(
+        &mut self,
+        max_depth: i64,
         is_maximizing: bool,
-        mut alpha: f64,
-        mut beta: f64,
+        table: &mut TranspositionTable,
+    ) -> <Self as GameStrategy>::Move {
+        search_root(
+            self,
+            max_depth,
+            is_maximizing,
+            &mut SearchContext::with_table(table),
+        )
+        .0
+    }
+
+    #[cfg(feature = "parallel")]
+    fn get_best_move_parallel(
+        &mut self,
         max_depth: i64,
-    ) -> f64 {
-        let avail: Vec<<T as GameStrategy>::Move> = self.get_available_moves();
-        if depth == 0 || self.is_game_complete() || avail.is_empty() {
-            return self.evaluate();
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move
+    where
+        Self: Clone + Send,
+        <Self as GameStrategy>::Move: Send,
+    {
+        let mut best_move: <Self as GameStrategy>::Move = self.get_a_sentinel_move();
+
+        if self.is_game_complete() {
+            return best_move;
         }
 
-        if is_maximizing {
-            let mut value = NEG_INF;
-            for idx in avail {
-                self.play(&idx, is_maximizing);
-                let score = self.minimax_score(depth - 1, !is_maximizing, alpha, beta, max_depth);
+        let branches: Vec<(Self, <Self as GameStrategy>::Move)> = self
+            .order_moves(self.get_root_moves(), !is_maximizing)
+            .into_iter()
+            .map(|mv| (self.clone(), mv))
+            .collect();
 
-                value = value.max(score);
-                alpha = alpha.max(score);
+        let scored: Vec<(<Self as GameStrategy>::Move, f64)> = branches
+            .into_par_iter()
+            .map(|(mut game, mv)| {
+                let value = score_move::<_, Plain>(
+                    &mut game,
+                    &mv,
+                    max_depth,
+                    !is_maximizing,
+                    -OrderedF64::MAX,
+                    OrderedF64::MAX,
+                    max_depth,
+                    &mut SearchContext::default(),
+                )
+                .0;
+                (mv, value)
+            })
+            .collect();
 
-                self.clear(&idx);
-                if beta <= alpha {
-                    break;
-                }
+        let mut best_move_val: f64 = NEG_INF;
+        for (mv, value) in scored {
+            if value >= best_move_val {
+                best_move_val = value;
+                best_move = mv;
             }
-            if value != 0. {
-                return value - (max_depth - depth) as f64;
-            }
-            value
-        } else {
-            let mut value = INF;
-            for idx in avail {
-                self.play(&idx, is_maximizing);
-                let score = self.minimax_score(depth - 1, !is_maximizing, alpha, beta, max_depth);
+        }
+        best_move
+    }
+
+    fn get_best_move_iterative(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move {
+        let mut best_move: <Self as GameStrategy>::Move = self.get_a_sentinel_move();
+        let mut search = SearchContext::default();
+
+        let max_depth = clamp_depth(self, max_depth);
+        for depth in max_depth.min(1)..=max_depth {
+            best_move = search_root(self, depth, is_maximizing, &mut search).0;
+        }
+        best_move
+    }
 
-                value = value.min(score);
-                beta = beta.min(score);
+    fn get_best_move_aspiration(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+        window: f64,
+    ) -> <Self as GameStrategy>::Move {
+        let mut best_move: <Self as GameStrategy>::Move = self.get_a_sentinel_move();
+        let mut search = SearchContext::default();
+        let mut previous_score: Option<f64> = None;
 
-                self.clear(&idx);
-                if beta <= alpha {
-                    break;
+        for depth in 0..=clamp_depth(self, max_depth) {
+            let (mv, score) = match previous_score {
+                Some(previous) => {
+                    let (alpha, beta) = (previous - window, previous + window);
+                    let (mv, score) =
+                        search_root_within(self, depth, is_maximizing, alpha, beta, &mut search);
+                    if score <= alpha || score >= beta {
+                        // The window was too narrow to hold the true score.
+                        search_root(self, depth, is_maximizing, &mut search)
+                    } else {
+                        (mv, score)
+                    }
                 }
-            }
+                None => search_root(self, depth, is_maximizing, &mut search),
+            };
+            best_move = mv;
+            previous_score = Some(score);
+        }
+        best_move
+    }
 
-            if value != 0. {
-                return value + (max_depth - depth) as f64;
-            }
-            value
+    #[cfg(feature = "std")]
+    fn get_best_move_timed(
+        &mut self,
+        budget: Duration,
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move {
+        let mut best_move: <Self as GameStrategy>::Move = self.get_a_sentinel_move();
+
+        if self.is_game_complete() {
+            return best_move;
         }
+
+        let mut search = SearchContext {
+            deadline: Some(Instant::now() + budget),
+            ..SearchContext::default()
+        };
+
+        for depth in 0.. {
+            search.depth_limited = false;
+            let (mv, _) = search_root(self, depth, is_maximizing, &mut search);
+
+            if search.timed_out {
+                if depth == 0 {
+                    // Not even a single ply could be searched,
+                    // so fall back to any legal move.
+                    best_move = self
+                        .order_moves(self.get_root_moves(), !is_maximizing)
+                        .into_iter()
+                        .next()
+                        .unwrap_or(best_move);
+                }
+                break;
+            }
+            best_move = mv;
+
+            // Searching deeper won't change anything
+            // once every line is played out to the end.
+            if !search.depth_limited {
+                break;
+            }
+        }
+        best_move
+    }
+
+    fn get_best_move_batched(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> <Self as GameStrategy>::Move
+    where
+        Self: Clone,
+    {
+        let best_move = self.get_a_sentinel_move();
+        if self.is_game_complete() {
+            return best_move;
+        }
+
+        // The player to move at the root is the
+        // opposite of the one `is_maximizing` names.
+        let mover = !is_maximizing;
+        let mut tree = vec![];
+        let mut leaves = vec![];
+        let root = expand_tree(self, mover, max_depth + 1, 0, &mut tree, &mut leaves);
+
+        let positions: Vec<&Self> = leaves.iter().map(|(game, _)| game).collect();
+        let scores: Vec<f64> = Self::evaluate_batch(&positions)
+            .into_iter()
+            .zip(&leaves)
+            .map(|(score, (game, ply))| terminal_adjusted(game, score, *ply))
+            .collect();
+
+        let mut best = (best_move, NEG_INF);
+        for (mv, child) in &tree[root].children {
+            let score = backed_up_score(&tree, *child, &scores);
+            let value = if mover { score } else { -score };
+            if value >= best.1 {
+                best = (mv.clone(), value);
+            }
+        }
+        best.0
+    }
+
+    fn get_principal_variation(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> Vec<<Self as GameStrategy>::Move> {
+        let mut search = SearchContext::new().with_principal_variation();
+        search_root(self, max_depth, is_maximizing, &mut search);
+        search.principal_variation().to_vec()
+    }
+
+    fn minimax_score(
+        &mut self,
+        depth: i64,
+        is_maximizing: bool,
+        alpha: f64,
+        beta: f64,
+        max_depth: i64,
+    ) -> f64 {
+        alpha_beta(
+            self,
+            depth,
+            is_maximizing,
+            alpha,
+            beta,
+            max_depth,
+            &mut SearchContext::default(),
+        )
+    }
+
+    fn minimax_score_with_table(
+        &mut self,
+        depth: i64,
+        is_maximizing: bool,
+        alpha: f64,
+        beta: f64,
+        max_depth: i64,
+        table: &mut TranspositionTable,
+    ) -> f64 {
+        alpha_beta(
+            self,
+            depth,
+            is_maximizing,
+            alpha,
+            beta,
+            max_depth,
+            &mut SearchContext::with_table(table),
+        )
+    }
+
+    fn minimax_score_with_context(
+        &mut self,
+        depth: i64,
+        is_maximizing: bool,
+        alpha: f64,
+        beta: f64,
+        max_depth: i64,
+        context: &mut SearchContext<<Self as GameStrategy>::Move>,
+    ) -> f64 {
+        alpha_beta(self, depth, is_maximizing, alpha, beta, max_depth, context)
+    }
+
+    fn quiescence(&mut self, alpha: f64, beta: f64, is_maximizing: bool) -> f64 {
+        let search = &mut SearchContext::default();
+        let (alpha, beta) = (OrderedF64(alpha), OrderedF64(beta));
+        let score = if is_maximizing {
+            quiesce::<_, Plain>(self, true, alpha, beta, 1, search)
+        } else {
+            -quiesce::<_, Plain>(self, false, -beta, -alpha, 1, search)
+        };
+        score.0
+    }
+}
+
+/// A function picking one of several moves.
+pub type PickMove<M> = Box<dyn Fn(&[M]) -> M>;
+
+/// How to choose among several moves tied for the best score.
+pub enum TieBreak<M> {
+    /// The first of them in the order they were searched.
+    First,
+    /// Any of them, drawn with the given seed so that
+    /// the choice is reproducible, or else with the clock
+    /// where `std` is available.
+    Random(Option<u64>),
+    /// Whichever the function picks.
+    Custom(PickMove<M>),
+}
+
+impl<M: Clone> TieBreak<M> {
+    /// Pick one of a non-empty list of moves.
+    pub fn choose(&self, moves: &[M]) -> M {
+        match self {
+            TieBreak::First => moves[0].clone(),
+            TieBreak::Random(seed) => {
+                let mut state = seed.unwrap_or_else(clock_seed);
+                let idx = splitmix64(&mut state) % moves.len() as u64;
+                moves[idx as usize].clone()
+            }
+            TieBreak::Custom(pick) => pick(moves),
+        }
+    }
+}
+
+/// A seed taken from the clock, or 0 without `std`.
+pub(crate) fn clock_seed() -> u64 {
+    #[cfg(feature = "std")]
+    let seed = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64);
+    #[cfg(not(feature = "std"))]
+    let seed = 0;
+    seed
+}
+
+/// How the engine should judge positions as it searches.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SearchConfig {
+    /// How much worse than equal the engine takes a drawn game
+    /// to be for itself, so that a positive contempt has it play
+    /// on in positions it could otherwise settle as a draw.
+    pub contempt: f64,
+    /// How many of the moves at every node, in the order
+    /// `order_moves` puts them, to search, if not all of them.
+    /// This trades finding the best move for searching deeper,
+    /// and is only as good as the move ordering.
+    pub max_branching: Option<usize>,
+    /// How many random playouts to score unfinished positions
+    /// at the depth limit by, with `rollout_evaluate`, instead
+    /// of `evaluate`, if any.
+    pub rollouts: Option<usize>,
+    /// The random state the playouts start from.
+    pub rollout_seed: u64,
+}
+
+impl SearchConfig {
+    pub fn with_contempt(self, contempt: f64) -> Self {
+        Self { contempt, ..self }
+    }
+
+    pub fn with_max_branching(self, max_branching: usize) -> Self {
+        Self {
+            max_branching: Some(max_branching),
+            ..self
+        }
+    }
+
+    pub fn with_rollouts(self, rollouts: usize, rollout_seed: u64) -> Self {
+        Self {
+            rollouts: Some(rollouts),
+            rollout_seed,
+            ..self
+        }
+    }
+
+    /// Drop the ordered moves past the branching limit.
+    fn limit_branching<M>(&self, moves: &mut Vec<M>) {
+        if let Some(max_branching) = self.max_branching {
+            moves.truncate(max_branching);
+        }
+    }
+}
+
+/// How much work a search did.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SearchStats {
+    /// The number of positions reached by playing a move.
+    pub nodes_visited: u64,
+    /// The number of times the remaining moves at
+    /// a position were skipped by a beta cutoff.
+    pub cutoffs: u64,
+    /// The most plies played out from the root in any line.
+    pub max_depth_reached: i64,
+}
+
+impl SearchStats {
+    /// Remember that a line was played out to a given ply.
+    fn reached(&mut self, ply: i64) {
+        self.max_depth_reached = self.max_depth_reached.max(ply);
+    }
+}
+
+/// How far along a search is, as reported
+/// each time a move at the root is scored.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchProgress<M> {
+    /// The number of root moves scored so far.
+    pub moves_searched: usize,
+    /// The number of root moves to score in all.
+    pub move_count: usize,
+    /// The depth the root moves are searched to.
+    pub depth: i64,
+    /// The best move found so far.
+    pub best_move: M,
+    /// The score of the best move so far,
+    /// from the maximizer's point of view.
+    pub score: f64,
+}
+
+/// The static evaluations of positions, by their hash and
+/// whose point of view they're from. Unlike the scores in a
+/// `TranspositionTable`, these are exact and depth-independent.
+#[cfg(feature = "std")]
+pub type EvalCache = HashMap<(u64, bool), f64>;
+
+/// The static evaluations of positions, by their hash and
+/// whose point of view they're from. Unlike the scores in a
+/// `TranspositionTable`, these are exact and depth-independent.
+#[cfg(not(feature = "std"))]
+pub type EvalCache = alloc::collections::BTreeMap<(u64, bool), f64>;
+
+/// How much each move has contributed to beta cutoffs.
+#[cfg(feature = "std")]
+pub type HistoryTable<M> = HashMap<M, u64>;
+
+/// How much each move has contributed to beta cutoffs.
+/// Without `std` there is no `HashMap`, and moves needn't
+/// be ordered, so this is a list searched from the front.
+#[cfg(not(feature = "std"))]
+pub type HistoryTable<M> = Vec<(M, u64)>;
+
+/// Everything a single search carries
+/// along besides the game itself.
+pub struct SearchContext<'a, M> {
+    /// Where to remember searched positions, if anywhere.
+    pub table: Option<&'a mut TranspositionTable>,
+    /// When to give up on the search, if ever.
+    #[cfg(feature = "std")]
+    pub deadline: Option<Instant>,
+    /// Whether the deadline passed mid-search, making
+    /// every score produced since meaningless.
+    pub timed_out: bool,
+    /// Whether any line was cut short by the depth limit
+    /// rather than by reaching the end of the game.
+    pub depth_limited: bool,
+    /// Up to two moves per ply that recently caused a beta
+    /// cutoff, which are tried first at sibling nodes.
+    pub killer_moves: Vec<[Option<M>; 2]>,
+    /// Whether to record and consult `killer_moves` at all.
+    pub use_killer_moves: bool,
+    /// How much each move has contributed to beta cutoffs
+    /// anywhere in the search, weighted toward cutoffs with
+    /// more depth left to search beneath them.
+    pub history: HistoryTable<M>,
+    /// Whether to record and consult `history` at all.
+    pub use_history: bool,
+    /// Move lists to reuse, one per ply, so that
+    /// positions don't each allocate their own.
+    pub move_buffers: Vec<Vec<M>>,
+    /// Whether to search every move but the first at
+    /// a position with a null window, as in `get_best_move_pvs`.
+    pub use_pvs: bool,
+    /// How much work the search has done so far.
+    pub stats: SearchStats,
+    /// How to judge positions.
+    pub config: SearchConfig,
+    /// The player to move at the root, whose draws
+    /// `config.contempt` counts against, once known.
+    pub root_mover: Option<bool>,
+    /// The random state of the playouts, if any.
+    pub rng: u64,
+    /// Where to report each root move scored, if anywhere.
+    pub progress: Option<&'a mut dyn FnMut(SearchProgress<M>)>,
+    /// Where to remember static evaluations, so that each
+    /// position is evaluated only once, if anywhere.
+    pub eval_cache: Option<EvalCache>,
+    /// The hashes of the positions on the line being searched,
+    /// from the root down, if repetitions are tracked. A position
+    /// already on it is scored as a draw rather than searched again.
+    pub path: Option<Vec<u64>>,
+    /// The best root move found by the last search completed
+    /// with this context, which the next one searches first.
+    pub pv_move: Option<M>,
+    /// The best line found from each ply on, if lines are
+    /// collected: a triangular array, where the line from a
+    /// ply is its best move followed by the line from the next.
+    pub pv: Option<Vec<Vec<M>>>,
+}
+
+impl<'a, M> Default for SearchContext<'a, M> {
+    fn default() -> Self {
+        Self {
+            table: None,
+            #[cfg(feature = "std")]
+            deadline: None,
+            timed_out: false,
+            depth_limited: false,
+            killer_moves: vec![],
+            use_killer_moves: true,
+            history: HistoryTable::default(),
+            use_history: false,
+            move_buffers: vec![],
+            use_pvs: false,
+            stats: SearchStats::default(),
+            config: SearchConfig::default(),
+            root_mover: None,
+            rng: 0,
+            progress: None,
+            eval_cache: None,
+            path: None,
+            pv_move: None,
+            pv: None,
+        }
+    }
+}
+
+impl<'a, M: Clone + Eq + Hash> SearchContext<'a, M> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_table(table: &'a mut TranspositionTable) -> Self {
+        Self {
+            table: Some(table),
+            ..Self::default()
+        }
+    }
+
+    pub fn without_killer_moves(self) -> Self {
+        Self {
+            use_killer_moves: false,
+            ..self
+        }
+    }
+
+    pub fn with_config(self, config: SearchConfig) -> Self {
+        Self {
+            config,
+            rng: config.rollout_seed,
+            ..self
+        }
+    }
+
+    pub fn with_history(self) -> Self {
+        Self {
+            use_history: true,
+            ..self
+        }
+    }
+
+    pub fn with_pvs(self) -> Self {
+        Self {
+            use_pvs: true,
+            ..self
+        }
+    }
+
+    pub fn with_progress(self, on_progress: &'a mut dyn FnMut(SearchProgress<M>)) -> Self {
+        Self {
+            progress: Some(on_progress),
+            ..self
+        }
+    }
+
+    pub fn with_eval_cache(self) -> Self {
+        Self {
+            eval_cache: Some(EvalCache::default()),
+            ..self
+        }
+    }
+
+    /// Score any position repeating one earlier on the line
+    /// being searched as a draw, which keeps games that can
+    /// repeat from searching the same cycle over and over.
+    /// Only meaningful for games whose `position_hash` tells
+    /// apart every pair of positions, including whose turn it is.
+    pub fn with_repetition_detection(self) -> Self {
+        Self {
+            path: Some(vec![]),
+            ..self
+        }
+    }
+
+    /// Collect the best line of play as the search goes,
+    /// to be read back with `principal_variation`.
+    pub fn with_principal_variation(self) -> Self {
+        Self {
+            pv: Some(vec![]),
+            ..self
+        }
+    }
+
+    /// The best line of play found by the last search,
+    /// starting with the root move, if lines are collected.
+    pub fn principal_variation(&self) -> &[M] {
+        self.pv
+            .as_ref()
+            .and_then(|pv| pv.first())
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Forget the line found from `ply` on.
+    fn clear_pv(&mut self, ply: usize) {
+        if let Some(line) = self.pv.as_mut().and_then(|pv| pv.get_mut(ply)) {
+            line.clear();
+        }
+    }
+
+    /// Make the line from `ply` start with `mv`,
+    /// followed by the line found from the next ply.
+    fn update_pv(&mut self, ply: usize, mv: &M) {
+        if let Some(pv) = self.pv.as_mut() {
+            if pv.len() < ply + 2 {
+                pv.resize_with(ply + 2, Vec::new);
+            }
+            let (lines, rest) = pv.split_at_mut(ply + 1);
+            let line = &mut lines[ply];
+            line.clear();
+            line.push(mv.clone());
+            line.extend_from_slice(&rest[0]);
+        }
+    }
+
+    /// Report a root move scored, if anyone is listening.
+    fn report(&mut self, progress: SearchProgress<M>) {
+        if let Some(on_progress) = self.progress.as_mut() {
+            on_progress(progress);
+        }
+    }
+
+    /// Check the clock, remembering if time ran out.
+    fn out_of_time(&mut self) -> bool {
+        #[cfg(feature = "std")]
+        if !self.timed_out {
+            if let Some(deadline) = self.deadline {
+                self.timed_out = Instant::now() >= deadline;
+            }
+        }
+        self.timed_out
+    }
+
+    /// Move the killer moves recorded for `ply`
+    /// to the front of `moves`, if they're among them.
+    fn order_killers(&self, ply: usize, mut moves: Vec<M>) -> Vec<M> {
+        if !self.use_killer_moves {
+            return moves;
+        }
+        if let Some(killers) = self.killer_moves.get(ply) {
+            for killer in killers.iter().rev().flatten() {
+                if let Some(idx) = moves.iter().position(|mv| mv == killer) {
+                    let mv = moves.remove(idx);
+                    moves.insert(0, mv);
+                }
+            }
+        }
+        moves
+    }
+
+    /// Sort `moves` by how much each has contributed to
+    /// cutoffs so far, keeping the order of those tied.
+    fn order_history(&self, mut moves: Vec<M>) -> Vec<M> {
+        if self.use_history {
+            moves.sort_by_key(|mv| core::cmp::Reverse(self.history_score(mv)));
+        }
+        moves
+    }
+
+    /// How much `mv` has contributed to cutoffs so far.
+    fn history_score(&self, mv: &M) -> u64 {
+        #[cfg(feature = "std")]
+        let score = self.history.get(mv).copied();
+        #[cfg(not(feature = "std"))]
+        let score = self.history.iter().find(|(m, _)| m == mv).map(|&(_, n)| n);
+        score.unwrap_or(0)
+    }
+
+    /// Remember that `mv` caused a beta cutoff
+    /// with `depth` plies left to search.
+    fn record_history(&mut self, depth: i64, mv: &M) {
+        if self.use_history {
+            let weight = (depth * depth) as u64;
+            #[cfg(feature = "std")]
+            {
+                *self.history.entry(mv.clone()).or_insert(0) += weight;
+            }
+            #[cfg(not(feature = "std"))]
+            match self.history.iter_mut().find(|(m, _)| m == mv) {
+                Some((_, n)) => *n += weight,
+                None => self.history.push((mv.clone(), weight)),
+            }
+        }
+    }
+
+    /// Lend out the move list kept for `ply`.
+    fn take_move_buffer(&mut self, ply: usize) -> Vec<M> {
+        if self.move_buffers.len() <= ply {
+            self.move_buffers.resize_with(ply + 1, Vec::new);
+        }
+        core::mem::take(&mut self.move_buffers[ply])
+    }
+
+    /// Keep a move list lent out for `ply` to use again.
+    fn return_move_buffer(&mut self, ply: usize, mut moves: Vec<M>) {
+        moves.clear();
+        self.move_buffers[ply] = moves;
+    }
+
+    /// Remember that `mv` caused a beta cutoff at `ply`.
+    fn record_killer(&mut self, ply: usize, mv: &M) {
+        if !self.use_killer_moves {
+            return;
+        }
+        if self.killer_moves.len() <= ply {
+            self.killer_moves.resize(ply + 1, [None, None]);
+        }
+        let killers = &mut self.killer_moves[ply];
+        if killers[0].as_ref() != Some(mv) {
+            killers[1] = killers[0].take();
+            killers[0] = Some(mv.clone());
+        }
+    }
+}
+
+/// Never search deeper than the game can last.
+fn clamp_depth<T: GameStrategy>(game: &T, max_depth: i64) -> i64 {
+    match game.max_depth_hint() {
+        Some(hint) => max_depth.min(hint),
+        None => max_depth,
+    }
+}
+
+/// The only move available, if there is just one, which
+/// needs no search to be found. Only the entry points that
+/// don't report a score take it as is, since scoring it
+/// still takes searching the position it leads to.
+fn forced_move<T: GameStrategy>(game: &T) -> Option<T::Move> {
+    if game.is_game_complete() || game.legal_move_count() != 1 {
+        return None;
+    }
+    game.get_available_moves().pop()
+}
+
+/// Score every move available at the root
+/// and pick the best one for the player to move.
+/// The score is from the maximizer's point of view.
+fn search_root<T: GameStrategy>(
+    game: &mut T,
+    max_depth: i64,
+    is_maximizing: bool,
+    search: &mut SearchContext<T::Move>,
+) -> (T::Move, f64) {
+    search_root_within(game, max_depth, is_maximizing, NEG_INF, INF, search)
+}
+
+/// The same as `search_root`, but every root move is
+/// searched within the given window, in the maximizer's
+/// terms. The best move and its score can only be trusted
+/// if the score lies strictly inside the window.
+fn search_root_within<T: GameStrategy>(
+    game: &mut T,
+    max_depth: i64,
+    is_maximizing: bool,
+    alpha: f64,
+    beta: f64,
+    search: &mut SearchContext<T::Move>,
+) -> (T::Move, f64) {
+    let (alpha, beta) = (OrderedF64(alpha), OrderedF64(beta));
+    let (best_move, score) =
+        search_root_by::<_, Plain>(game, max_depth, is_maximizing, alpha, beta, search);
+    (best_move, score.0)
+}
+
+/// The same as `search_root_within`, but with positions
+/// valued by `V`, and scores of its type.
+pub(crate) fn search_root_by<T: GameStrategy, V: Valuation<T>>(
+    game: &mut T,
+    max_depth: i64,
+    is_maximizing: bool,
+    alpha: V::Score,
+    beta: V::Score,
+    search: &mut SearchContext<T::Move>,
+) -> (T::Move, V::Score) {
+    let mut best_move: T::Move = game.get_a_sentinel_move();
+    search.clear_pv(0);
+
+    if game.is_game_complete() {
+        return (best_move, V::evaluate(game));
+    }
+    let max_depth = clamp_depth(game, max_depth);
+
+    // The player to move at the root is the
+    // opposite of the one `is_maximizing` names.
+    let mover = !is_maximizing;
+    search.root_mover = Some(mover);
+
+    let (alpha, beta) = if mover {
+        (alpha, beta)
+    } else {
+        (-beta, -alpha)
+    };
+    let mut best_move_val = -V::Score::MAX;
+
+    // Each move keeps its place in the game's order, so that
+    // ties are broken the same way whichever move goes first.
+    let mut moves: Vec<(usize, T::Move)> = game
+        .order_moves(game.get_root_moves(), mover)
+        .into_iter()
+        .enumerate()
+        .collect();
+    if let Some(pv_move) = &search.pv_move {
+        if let Some(idx) = moves.iter().position(|(_, mv)| mv == pv_move) {
+            let entry = moves.remove(idx);
+            moves.insert(0, entry);
+        }
+    }
+    search.config.limit_branching(&mut moves);
+    let move_count = moves.len();
+    let mut best_rank = 0;
+    enter_path(game, search);
+    for (idx, (rank, mv)) in moves.into_iter().enumerate() {
+        let value = score_move::<_, V>(game, &mv, max_depth, mover, alpha, beta, max_depth, search);
+        if search.timed_out {
+            break;
+        }
+        if value > best_move_val || (value == best_move_val && rank >= best_rank) {
+            search.update_pv(0, &mv);
+            best_move_val = value;
+            best_move = mv;
+            best_rank = rank;
+        }
+        search.report(SearchProgress {
+            moves_searched: idx + 1,
+            move_count,
+            depth: max_depth,
+            best_move: best_move.clone(),
+            score: if mover { best_move_val } else { -best_move_val }.to_f64(),
+        });
+    }
+    leave_path(search);
+    if !search.timed_out {
+        search.pv_move = Some(best_move.clone());
+    }
+
+    if mover {
+        (best_move, best_move_val)
+    } else {
+        (best_move, -best_move_val)
+    }
+}
+
+/// How much more likely `get_move_with_skill` is to play
+/// the best move than the worst at a skill of a half.
+/// The odds are `e` to this power, and grow without
+/// bound as the skill approaches 1.
+#[cfg(feature = "std")]
+const SKILL_STEEPNESS: f64 = 5.;
+
+/// The fraction of a win (or loss) score given up
+/// for every ply it takes to reach the result.
+const PLY_PENALTY: f64 = 1e-3;
+
+/// The number of plies to the end of the game a score
+/// returned by the search stands for, if it is a forced
+/// result: positive if the maximizer wins, and negative
+/// if the minimizer does. `win_score` is the game's
+/// `WIN_SCORE`. This undoes `terminal_adjusted`.
+pub fn mate_in(score: f64, win_score: f64) -> Option<i64> {
+    // Any result a search could reach scores well
+    // above half a win, and no evaluation does.
+    if score.abs() < win_score / 2. {
+        return None;
+    }
+    // Round to the nearest ply; `f64::round` needs `std`.
+    let plies = ((win_score - score.abs()) / (win_score * PLY_PENALTY) + 0.5) as i64;
+    Some(if score > 0. { plies } else { -plies })
+}
+
+/// Pull a decisive score toward zero by the number
+/// of plies it took to reach, so that a win at ply `d`
+/// scores `WIN_SCORE * (1 - d / 1000)`, e.g. `1000 - d`,
+/// and a loss likewise. This makes the engine
+/// prefer quicker wins and slower losses.
+/// Scores of unfinished or drawn games are left alone.
+fn terminal_adjusted<T: GameStrategy>(game: &T, score: f64, ply: i64) -> f64 {
+    if !game.is_game_complete() || game.is_game_tied() {
+        score
+    } else if score >= T::WIN_SCORE {
+        score - T::WIN_SCORE * PLY_PENALTY * ply as f64
+    } else if score <= T::LOSS_SCORE {
+        score - T::LOSS_SCORE * PLY_PENALTY * ply as f64
+    } else {
+        score
+    }
+}
+
+/// How the search values positions: the type of
+/// score it compares, and how positions are scored.
+pub(crate) trait Valuation<T: GameStrategy> {
+    type Score: Score;
+
+    /// The score of a position from the maximizer's point of view.
+    fn evaluate(game: &T) -> Self::Score;
+
+    /// The score of a position from the given player's point of view.
+    fn evaluate_for(game: &T, maximizer: bool) -> Self::Score;
+
+    /// Pull a decisive score, from the maximizer's point of view,
+    /// toward zero by the number of plies it took to reach.
+    fn terminal_adjusted(game: &T, score: Self::Score, ply: i64) -> Self::Score;
+}
+
+/// Values positions by `GameStrategy::evaluate`.
+pub(crate) struct Plain;
+
+impl<T: GameStrategy> Valuation<T> for Plain {
+    type Score = OrderedF64;
+
+    fn evaluate(game: &T) -> OrderedF64 {
+        OrderedF64(game.evaluate())
+    }
+
+    fn evaluate_for(game: &T, maximizer: bool) -> OrderedF64 {
+        OrderedF64(game.evaluate_for(maximizer))
+    }
+
+    fn terminal_adjusted(game: &T, score: OrderedF64, ply: i64) -> OrderedF64 {
+        OrderedF64(terminal_adjusted(game, score.0, ply))
+    }
+}
+
+/// The score of a position the search goes no deeper into,
+/// from the given player's point of view, where a drawn game
+/// counts against the player to move at the root by the
+/// configured contempt.
+fn leaf_score<T: GameStrategy, V: Valuation<T>>(
+    game: &T,
+    maximizer: bool,
+    ply: i64,
+    search: &mut SearchContext<T::Move>,
+) -> V::Score {
+    let contempt = search.config.contempt;
+    if let Some(root_mover) = search.root_mover {
+        if contempt != 0. && game.is_game_complete() && game.is_game_tied() {
+            let contempt = V::Score::from_f64(contempt);
+            return if root_mover == maximizer {
+                -contempt
+            } else {
+                contempt
+            };
+        }
+    }
+    // Decisive scores are adjusted in the maximizer's terms.
+    let score = match search.eval_cache.as_mut() {
+        Some(cache) => V::Score::from_f64(
+            *cache
+                .entry((game.position_hash(), maximizer))
+                .or_insert_with(|| V::evaluate_for(game, maximizer).to_f64()),
+        ),
+        None => V::evaluate_for(game, maximizer),
+    };
+    if maximizer {
+        V::terminal_adjusted(game, score, ply)
+    } else {
+        -V::terminal_adjusted(game, -score, ply)
+    }
+}
+
+/// Score a position from the maximizer's point of view.
+fn alpha_beta<T: GameStrategy>(
+    game: &mut T,
+    depth: i64,
+    is_maximizing: bool,
+    alpha: f64,
+    beta: f64,
+    max_depth: i64,
+    search: &mut SearchContext<T::Move>,
+) -> f64 {
+    let (alpha, beta) = (OrderedF64(alpha), OrderedF64(beta));
+    enter_path(game, search);
+    let score = if is_maximizing {
+        negamax::<_, Plain>(game, depth, true, alpha, beta, max_depth, search)
+    } else {
+        -negamax::<_, Plain>(game, depth, false, -beta, -alpha, max_depth, search)
+    };
+    leave_path(search);
+    score.0
+}
+
+/// The alpha-beta search shared by every entry point,
+/// in its negamax form: the score is from the point of view
+/// of the player to move, so each ply negates its children's
+/// scores and swaps their bounds.
+/// If the search runs out of time, it unwinds
+/// as quickly as possible and its score must be ignored.
+fn negamax<T: GameStrategy, V: Valuation<T>>(
+    game: &mut T,
+    depth: i64,
+    is_maximizing: bool,
+    mut alpha: V::Score,
+    mut beta: V::Score,
+    max_depth: i64,
+    search: &mut SearchContext<T::Move>,
+) -> V::Score {
+    if search.out_of_time() {
+        return V::Score::from_f64(0.);
+    }
+    let ply = max_depth - depth + 1;
+    search.stats.reached(ply);
+    search.clear_pv(ply as usize);
+
+    let terminal = game.is_terminal();
+    if depth == 0 || terminal {
+        if !terminal {
+            search.depth_limited = true;
+            if !game.is_quiet() {
+                return quiesce::<_, V>(game, is_maximizing, alpha, beta, ply, search);
+            }
+            if let Some(rollouts) = search.config.rollouts {
+                let score = V::Score::from_f64(rollout_in_place(game, rollouts, &mut search.rng));
+                return if is_maximizing { score } else { -score };
+            }
+        }
+        return leaf_score::<_, V>(game, is_maximizing, ply, search);
+    }
+    let (alpha_orig, beta_orig) = (alpha, beta);
+    let hash = search.table.as_ref().map(|_| game.position_hash());
+
+    if let (Some(table), Some(hash)) = (search.table.as_deref(), hash) {
+        if let Some(entry) = table.probe(hash, is_maximizing, depth) {
+            let score = V::Score::from_f64(entry.score);
+            match entry.node_type {
+                NodeType::Exact => return score,
+                NodeType::LowerBound => alpha = alpha.max(score),
+                NodeType::UpperBound => beta = beta.min(score),
+            }
+            if beta <= alpha {
+                return score;
+            }
+        }
+    }
+
+    let killer_ply = (ply - 1) as usize;
+    let mut avail = search.take_move_buffer(killer_ply);
+    game.fill_available_moves(&mut avail);
+    let avail = game.order_moves(avail, is_maximizing);
+    let avail = search.order_history(avail);
+    let mut avail = search.order_killers(killer_ply, avail);
+    // Only trim the moves once the heuristics have had
+    // their say on which of them are worth keeping.
+    search.config.limit_branching(&mut avail);
+
+    let mut value = -V::Score::MAX;
+    for (idx, mv) in avail.iter().enumerate() {
+        let score = if search.use_pvs && idx > 0 {
+            // Prove the move no better than the best so far,
+            // and only if that fails, find out by how much.
+            let score = score_move::<_, V>(
+                game,
+                mv,
+                depth - 1,
+                is_maximizing,
+                alpha,
+                alpha.above(),
+                max_depth,
+                search,
+            );
+            if score > alpha && score < beta && !search.timed_out {
+                score_move::<_, V>(
+                    game,
+                    mv,
+                    depth - 1,
+                    is_maximizing,
+                    alpha,
+                    beta,
+                    max_depth,
+                    search,
+                )
+            } else {
+                score
+            }
+        } else {
+            score_move::<_, V>(
+                game,
+                mv,
+                depth - 1,
+                is_maximizing,
+                alpha,
+                beta,
+                max_depth,
+                search,
+            )
+        };
+        if search.timed_out {
+            return V::Score::from_f64(0.);
+        }
+
+        if score > alpha {
+            search.update_pv(ply as usize, mv);
+        }
+        value = value.max(score);
+        alpha = alpha.max(score);
+        if beta <= alpha {
+            search.stats.cutoffs += 1;
+            search.record_killer(killer_ply, mv);
+            search.record_history(depth, mv);
+            break;
+        }
+    }
+    search.return_move_buffer(killer_ply, avail);
+
+    let node_type = if value <= alpha_orig {
+        NodeType::UpperBound
+    } else if value >= beta_orig {
+        NodeType::LowerBound
+    } else {
+        NodeType::Exact
+    };
+
+    if let (Some(table), Some(hash)) = (search.table.as_deref_mut(), hash) {
+        table.store(
+            hash,
+            is_maximizing,
+            TranspositionEntry {
+                depth,
+                score: value.to_f64(),
+                node_type,
+            },
+        );
+    }
+    value
+}
+
+/// Play a move for the given player, score the position it
+/// leads to from that player's point of view, and take it back.
+/// The position is searched to `depth` with the opponent to
+/// move next, unless the move lets the same player go again.
+#[allow(clippy::too_many_arguments)]
+fn score_move<T: GameStrategy, V: Valuation<T>>(
+    game: &mut T,
+    mv: &T::Move,
+    depth: i64,
+    is_maximizing: bool,
+    alpha: V::Score,
+    beta: V::Score,
+    max_depth: i64,
+    search: &mut SearchContext<T::Move>,
+) -> V::Score {
+    let passes_turn = game.move_passes_turn(mv);
+    game.play(mv, is_maximizing);
+    search.stats.nodes_visited += 1;
+    let score = if !enter_path(game, search) {
+        // A repetition ends the line here.
+        search.clear_pv((max_depth - depth + 1) as usize);
+        V::Score::from_f64(0.)
+    } else {
+        let score = if passes_turn {
+            -negamax::<_, V>(
+                game,
+                depth,
+                !is_maximizing,
+                -beta,
+                -alpha,
+                max_depth,
+                search,
+            )
+        } else {
+            negamax::<_, V>(game, depth, is_maximizing, alpha, beta, max_depth, search)
+        };
+        leave_path(search);
+        score
+    };
+    take_back(game, mv);
+    score
+}
+
+/// Put the current position on the line being searched,
+/// if repetitions are tracked. Returns `false`, leaving
+/// the line as it was, if the position is on it already.
+fn enter_path<T: GameStrategy>(game: &T, search: &mut SearchContext<T::Move>) -> bool {
+    if let Some(path) = search.path.as_mut() {
+        let hash = game.position_hash();
+        if path.contains(&hash) {
+            return false;
+        }
+        path.push(hash);
+    }
+    true
+}
+
+/// Take the last position entered off the line being searched.
+fn leave_path<M>(search: &mut SearchContext<M>) {
+    if let Some(path) = search.path.as_mut() {
+        path.pop();
+    }
+}
+
+/// Take back a move just played, by undoing the last move
+/// if the game can, and by clearing the given one if not.
+fn take_back<T: GameStrategy>(game: &mut T, mv: &T::Move) {
+    if game.undo_move().is_err() {
+        game.clear(mv);
+    }
+}
+
+/// Estimate a position as `GameStrategy::rollout_evaluate` does,
+/// but playing out on the game itself and taking every move back,
+/// as the rest of the search does, since it can't clone the game.
+fn rollout_in_place<T: GameStrategy>(game: &mut T, n: usize, rng: &mut u64) -> f64 {
+    if n == 0 {
+        return game.evaluate();
+    }
+    let mut total = 0.;
+    let mut played = vec![];
+    for _ in 0..n {
+        while !game.is_terminal() && played.len() < MAX_PLAYOUT_PLIES {
+            let mv = random_move(game, rng);
+            let maximizer = game.current_player();
+            game.play(&mv, maximizer);
+            played.push(mv);
+        }
+        total += playout_score(game);
+        while let Some(mv) = played.pop() {
+            take_back(game, &mv);
+        }
+    }
+    total / n as f64
+}
+
+/// A position in a tree expanded ahead of being scored.
+struct TreeNode<M> {
+    /// Whether the maximizer is to move here.
+    maximizer: bool,
+    /// The moves out of this position, along with
+    /// the nodes they lead to.
+    children: Vec<(M, usize)>,
+    /// The index among the leaves, if this is one.
+    leaf: Option<usize>,
+}
+
+/// Expand every line from the current position `depth`
+/// plies deep, or until it ends, appending the nodes to
+/// `tree` and a copy of every leaf position, with how many
+/// plies from the root it is, to `leaves`.
+/// Returns the index of the node for the current position.
+fn expand_tree<T: GameStrategy + Clone>(
+    game: &mut T,
+    maximizer: bool,
+    depth: i64,
+    ply: i64,
+    tree: &mut Vec<TreeNode<T::Move>>,
+    leaves: &mut Vec<(T, i64)>,
+) -> usize {
+    let idx = tree.len();
+    tree.push(TreeNode {
+        maximizer,
+        children: vec![],
+        leaf: None,
+    });
+
+    if depth == 0 || game.is_terminal() {
+        tree[idx].leaf = Some(leaves.len());
+        leaves.push((game.clone(), ply));
+        return idx;
+    }
+
+    let moves = if ply == 0 {
+        game.get_root_moves()
+    } else {
+        game.get_available_moves()
+    };
+    for mv in game.order_moves(moves, maximizer) {
+        let passes_turn = game.move_passes_turn(&mv);
+        game.play(&mv, maximizer);
+        let next = if passes_turn { !maximizer } else { maximizer };
+        let child = expand_tree(game, next, depth - 1, ply + 1, tree, leaves);
+        take_back(game, &mv);
+        tree[idx].children.push((mv, child));
+    }
+    idx
+}
+
+/// The minimax score of a node in an expanded tree, from
+/// the maximizer's point of view, given the leaves' scores.
+fn backed_up_score<M>(tree: &[TreeNode<M>], idx: usize, scores: &[f64]) -> f64 {
+    let node = &tree[idx];
+    if let Some(leaf) = node.leaf {
+        return scores[leaf];
+    }
+    let children = node
+        .children
+        .iter()
+        .map(|&(_, child)| backed_up_score(tree, child, scores));
+    if node.maximizer {
+        children.fold(NEG_INF, f64::max)
+    } else {
+        children.fold(INF, f64::min)
+    }
+}
+
+/// Extend a search past its depth limit, in negamax form,
+/// by playing only noisy moves until the position is quiet.
+/// The player to move may always "stand pat" on the static
+/// evaluation instead of making any of them.
+fn quiesce<T: GameStrategy, V: Valuation<T>>(
+    game: &mut T,
+    is_maximizing: bool,
+    mut alpha: V::Score,
+    beta: V::Score,
+    ply: i64,
+    search: &mut SearchContext<T::Move>,
+) -> V::Score {
+    if search.out_of_time() {
+        return V::Score::from_f64(0.);
+    }
+    search.stats.reached(ply);
+
+    let stand_pat = leaf_score::<_, V>(game, is_maximizing, ply, search);
+    if game.is_game_complete() || game.is_quiet() || stand_pat >= beta {
+        return stand_pat;
+    }
+    alpha = alpha.max(stand_pat);
+
+    let mut value = stand_pat;
+    for mv in game.order_moves(game.get_noisy_moves(), is_maximizing) {
+        let passes_turn = game.move_passes_turn(&mv);
+        game.play(&mv, is_maximizing);
+        search.stats.nodes_visited += 1;
+        let score = if passes_turn {
+            -quiesce::<_, V>(game, !is_maximizing, -beta, -alpha, ply + 1, search)
+        } else {
+            quiesce::<_, V>(game, is_maximizing, alpha, beta, ply + 1, search)
+        };
+        take_back(game, &mv);
+        if search.timed_out {
+            return V::Score::from_f64(0.);
+        }
+
+        value = value.max(score);
+        alpha = alpha.max(score);
+        if beta <= alpha {
+            search.stats.cutoffs += 1;
+            break;
+        }
+    }
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A single pile from which each player takes one or
+    /// two objects in turn. Whoever takes the last one wins.
+    /// Scored with `±1` rather than the usual `±1000`.
+    struct Subtraction {
+        pile: usize,
+        /// How many were taken each turn, and whether by the maximizer.
+        taken: Vec<(usize, bool)>,
+    }
+
+    impl GameStrategy for Subtraction {
+        type Player = bool;
+        type Move = usize;
+        type Board = usize;
+
+        const WIN_SCORE: f64 = 1.;
+        const LOSS_SCORE: f64 = -1.;
+
+        fn evaluate(&self) -> f64 {
+            match self.get_winner() {
+                Some(true) => Self::WIN_SCORE,
+                Some(false) => Self::LOSS_SCORE,
+                None => 0.,
+            }
+        }
+        fn get_winner(&self) -> Option<bool> {
+            if self.pile == 0 {
+                self.taken.last().map(|&(_, maximizer)| maximizer)
+            } else {
+                None
+            }
+        }
+        fn is_game_tied(&self) -> bool {
+            false
+        }
+        fn is_game_complete(&self) -> bool {
+            self.pile == 0
+        }
+        fn get_available_moves(&self) -> Vec<usize> {
+            (1..=self.pile.min(2)).collect()
+        }
+        fn play(&mut self, mv: &usize, maximizer: bool) {
+            self.pile -= mv;
+            self.taken.push((*mv, maximizer));
+        }
+        fn clear(&mut self, mv: &usize) {
+            self.pile += mv;
+            self.taken.pop();
+        }
+        fn reset(&mut self) {
+            while let Some((mv, _)) = self.taken.pop() {
+                self.pile += mv;
+            }
+        }
+        fn current_player(&self) -> bool {
+            !matches!(self.taken.last(), Some(&(_, true)))
+        }
+        fn get_board(&self) -> &usize {
+            &self.pile
+        }
+        fn is_a_valid_move(&self, mv: &usize) -> bool {
+            (1..=2).contains(mv) && *mv <= self.pile
+        }
+        fn get_a_sentinel_move(&self) -> usize {
+            0
+        }
+        fn position_hash(&self) -> u64 {
+            self.pile as u64
+        }
+    }
+
+    fn pile(pile: usize) -> Subtraction {
+        Subtraction {
+            pile,
+            taken: vec![],
+        }
+    }
+
+    /// A token on a ring of four squares, which each player
+    /// moves one square either way in turn, forever. The
+    /// maximizer is always a little ahead on evaluation.
+    struct Ring {
+        square: usize,
+        /// The squares the token has left, in order.
+        history: Vec<usize>,
+    }
+
+    impl GameStrategy for Ring {
+        type Player = bool;
+        type Move = usize;
+        type Board = usize;
+
+        fn evaluate(&self) -> f64 {
+            1.
+        }
+        fn get_winner(&self) -> Option<bool> {
+            None
+        }
+        fn is_game_tied(&self) -> bool {
+            false
+        }
+        fn is_game_complete(&self) -> bool {
+            false
+        }
+        fn get_available_moves(&self) -> Vec<usize> {
+            vec![(self.square + 1) % 4, (self.square + 3) % 4]
+        }
+        fn play(&mut self, mv: &usize, _maximizer: bool) {
+            self.history.push(self.square);
+            self.square = *mv;
+        }
+        fn clear(&mut self, _mv: &usize) {
+            if let Some(square) = self.history.pop() {
+                self.square = square;
+            }
+        }
+        fn reset(&mut self) {
+            self.square = 0;
+            self.history.clear();
+        }
+        fn current_player(&self) -> bool {
+            self.square.is_multiple_of(2)
+        }
+        fn get_board(&self) -> &usize {
+            &self.square
+        }
+        fn is_a_valid_move(&self, mv: &usize) -> bool {
+            self.get_available_moves().contains(mv)
+        }
+        fn get_a_sentinel_move(&self) -> usize {
+            4
+        }
+        fn position_hash(&self) -> u64 {
+            // The square's parity tells whose turn it is.
+            self.square as u64
+        }
+    }
+
+    #[test]
+    fn a_repeated_position_is_scored_as_a_draw() {
+        let mut ring = Ring {
+            square: 0,
+            history: vec![],
+        };
+        assert_eq!(ring.minimax_score(6, true, NEG_INF, INF, 6), 1.);
+
+        // The minimizer can always step back to where the
+        // token just was, which repeats the position.
+        let mut search = SearchContext::new().with_repetition_detection();
+        let score = ring.minimax_score_with_context(6, true, NEG_INF, INF, 6, &mut search);
+        assert_eq!(score, 0.);
+        assert_eq!(search.path, Some(vec![]));
+        assert_eq!(ring.square, 0);
+    }
+
+    #[test]
+    fn no_skill_plays_any_move_as_often_as_any_other() {
+        use crate::games::TicTacToe;
+
+        let mut ttt = TicTacToe::new(3);
+        let mut rng = 11;
+        let mut counts = [0; 9];
+        for _ in 0..9000 {
+            counts[ttt.get_move_with_skill(9, true, 0., &mut rng)] += 1;
+        }
+        assert!(counts.iter().all(|&count| (850..1150).contains(&count)));
+    }
+
+    #[test]
+    fn skill_leans_toward_the_better_moves() {
+        use crate::games::TicTacToe;
+
+        // The minimizer must block at 2 or lose.
+        let mut ttt = TicTacToe::from_str_board("oo-\nx--\n---").unwrap();
+        let best_move = ttt.get_best_move(9, true);
+        let mut rng = 5;
+        assert_eq!(ttt.get_move_with_skill(9, true, 1., &mut rng), best_move);
+
+        let blocks = |ttt: &mut TicTacToe, skill: f64, rng: &mut u64| {
+            (0..200)
+                .filter(|_| ttt.get_move_with_skill(9, true, skill, rng) == 2)
+                .count()
+        };
+        let (clumsy, skilled) = (
+            blocks(&mut ttt, 0.2, &mut rng),
+            blocks(&mut ttt, 0.9, &mut rng),
+        );
+        assert!(clumsy < skilled);
+        assert!(skilled > 190);
+    }
+
+    #[test]
+    fn the_eval_cache_evaluates_each_position_once() {
+        use crate::games::TicTacToe;
+        use crate::strategy::counting::CountingGame;
+
+        let mut ttt = TicTacToe::new(4);
+        for (idx, mv) in [0, 5, 10, 15, 3, 12].iter().enumerate() {
+            ttt.play(mv, idx % 2 == 0);
+        }
+
+        let mut plain = CountingGame::new(ttt.clone());
+        let expected = plain.get_best_move_with_score(4, true);
+
+        let mut cached = CountingGame::new(ttt);
+        let mut search = SearchContext::new().with_eval_cache();
+        let found = search_root(&mut cached, 4, true, &mut search);
+
+        // Positions reached by different move orders are
+        // scored from the cache after the first time.
+        let cache = search.eval_cache.unwrap();
+        assert_eq!(found, expected);
+        assert_eq!(cached.evaluations.get(), cache.len() as u64);
+        assert!(cached.evaluations.get() < plain.evaluations.get());
+    }
+
+    #[test]
+    fn the_last_best_move_is_searched_first() {
+        let mut searched = vec![];
+        let mut on_progress = |progress: SearchProgress<usize>| {
+            searched.push(progress.best_move);
+        };
+        let mut search = SearchContext::new().with_progress(&mut on_progress);
+        let mut game = pile(2);
+
+        // Taking both tokens wins, though it comes second in order.
+        let (best_move, _) = search_root(&mut game, 1, false, &mut search);
+        assert_eq!(search.pv_move, Some(2));
+        let (again, _) = search_root(&mut game, 2, false, &mut search);
+        drop(search);
+
+        assert_eq!((best_move, again), (2, 2));
+        assert_eq!(searched, vec![1, 2, 2, 2]);
+    }
+
+    #[test]
+    fn progress_is_reported_once_per_root_move() {
+        let mut reports = vec![];
+        let best_move = pile(4).get_best_move_with_progress(5, false, |progress| {
+            reports.push(progress);
+        });
+        assert_eq!(reports.len(), 2);
+        for (idx, progress) in reports.iter().enumerate() {
+            assert_eq!((progress.moves_searched, progress.move_count), (idx + 1, 2));
+            assert_eq!(progress.depth, 5);
+        }
+        let last = reports.last().unwrap();
+        assert_eq!(last.best_move, best_move);
+        assert_eq!(last.score, pile(4).get_best_move_with_score(5, false).1);
+    }
+
+    #[test]
+    fn a_windowed_search_still_finds_a_move() {
+        for n in 1..8 {
+            let best_move = pile(n).get_best_move(6, false);
+            let wide = pile(n).get_best_move_windowed(6, false, NEG_INF, INF);
+            assert_eq!(wide, best_move);
+
+            // A null window around a draw only tells wins from losses.
+            let mut game = pile(n);
+            let probe = game.get_best_move_windowed(6, false, -1e-9, 0.);
+            assert!(game.is_a_valid_move(&probe));
+        }
+    }
+
+    #[test]
+    fn win_distance_respects_a_custom_score_scale() {
+        // Taking both objects wins on the spot.
+        let (best_move, score) = pile(2).get_best_move_with_score(5, false);
+        assert_eq!(best_move, 2);
+        assert!((score - (1. - PLY_PENALTY)).abs() < 1e-9);
+
+        // Leaving a multiple of three wins two plies later.
+        let (best_move, score) = pile(4).get_best_move_with_score(5, false);
+        assert_eq!(best_move, 1);
+        assert!((score - (1. - 3. * PLY_PENALTY)).abs() < 1e-9);
+
+        // Whatever the maximizer does from a multiple of three, it loses.
+        let (_, score) = pile(3).get_best_move_with_score(5, false);
+        assert!(score < 0. && score > -1.);
+    }
+
+    #[test]
+    fn a_beam_of_one_plays_the_first_ordered_move() {
+        // The full search finds the win by leaving a multiple
+        // of three, but a beam of one only ever looks at the
+        // first move in order, taking a single object.
+        assert_eq!(pile(5).get_best_move(5, false), 2);
+
+        let config = SearchConfig::default().with_max_branching(1);
+        let mut search = SearchContext::new().with_config(config);
+        let best_move = pile(5).get_best_move_with_context(5, false, &mut search);
+        assert_eq!(best_move, 1);
+        assert_eq!(search.stats.nodes_visited, 5);
+
+        let config = SearchConfig::default().with_max_branching(2);
+        assert_eq!(pile(5).get_best_move_with_config(5, false, config), 2);
+    }
+
+    #[test]
+    fn a_beam_keeps_killer_moves_rather_than_dropping_them() {
+        // Taking two is a killer one ply below the root, so the
+        // beam keeps it there, ending the line a ply sooner.
+        let config = SearchConfig::default().with_max_branching(1);
+        let mut search = SearchContext::new().with_config(config);
+        search.killer_moves = vec![[Some(2), None]];
+        let best_move = pile(5).get_best_move_with_context(5, false, &mut search);
+        assert_eq!(best_move, 1);
+        assert_eq!(search.stats.nodes_visited, 4);
+    }
+
+    #[test]
+    fn forced_moves_are_played_without_searching() {
+        // Only one object is left to take, winning on the spot.
+        let (best_move, stats) = pile(1).get_best_move_with_stats(5, false);
+        assert_eq!(best_move, 1);
+        assert_eq!(stats.nodes_visited, 0);
+        assert_eq!(pile(1).get_best_move(5, false), 1);
+
+        let (_, score) = pile(1).get_best_move_with_score(5, false);
+        assert!((score - (1. - PLY_PENALTY)).abs() < 1e-9);
+
+        let mut over = pile(1);
+        over.play(&1, true);
+        assert_eq!(over.get_best_move(5, false), over.get_a_sentinel_move());
+    }
+
+    #[test]
+    fn tiebreak_chooses_among_equally_scored_moves() {
+        // Both moves from a multiple of three lose two plies later.
+        assert_eq!(pile(3).get_best_moves(5, false), vec![1, 2]);
+
+        assert_eq!(
+            pile(3).get_best_move_with_tiebreak(5, false, &TieBreak::First),
+            1
+        );
+        let largest = TieBreak::Custom(Box::new(|moves: &[usize]| moves[moves.len() - 1]));
+        assert_eq!(pile(3).get_best_move_with_tiebreak(5, false, &largest), 2);
+
+        for seed in 0..8 {
+            let random = TieBreak::Random(Some(seed));
+            let first = pile(3).get_best_move_with_tiebreak(5, false, &random);
+            assert_eq!(
+                pile(3).get_best_move_with_tiebreak(5, false, &random),
+                first
+            );
+        }
+
+        // A lone best move is chosen whatever the policy.
+        let random = TieBreak::Random(None);
+        assert_eq!(pile(4).get_best_move_with_tiebreak(5, false, &random), 1);
+        assert_eq!(pile(0).get_best_move_with_tiebreak(5, false, &random), 0);
+    }
+
+    /// The first player either agrees to a draw, ending the
+    /// game, or plays on into a position slightly worse for them.
+    struct DrawOrPlayOn {
+        played: Vec<usize>,
+    }
+
+    impl GameStrategy for DrawOrPlayOn {
+        type Player = bool;
+        type Move = usize;
+        type Board = Vec<usize>;
+
+        fn evaluate(&self) -> f64 {
+            match self.played.first() {
+                Some(1) => -0.5,
+                _ => 0.,
+            }
+        }
+        fn get_winner(&self) -> Option<bool> {
+            None
+        }
+        fn is_game_tied(&self) -> bool {
+            self.played.first() == Some(&0)
+        }
+        fn is_game_complete(&self) -> bool {
+            self.is_game_tied() || self.played.len() >= 4
+        }
+        fn get_available_moves(&self) -> Vec<usize> {
+            if self.is_game_complete() {
+                vec![]
+            } else if self.played.is_empty() {
+                vec![0, 1]
+            } else {
+                vec![1]
+            }
+        }
+        fn play(&mut self, mv: &usize, _maximizer: bool) {
+            self.played.push(*mv);
+        }
+        fn clear(&mut self, _mv: &usize) {
+            self.played.pop();
+        }
+        fn reset(&mut self) {
+            self.played.clear();
+        }
+        fn current_player(&self) -> bool {
+            self.played.len() % 2 != 1
+        }
+        fn get_board(&self) -> &Vec<usize> {
+            &self.played
+        }
+        fn is_a_valid_move(&self, mv: &usize) -> bool {
+            self.get_available_moves().contains(mv)
+        }
+        fn get_a_sentinel_move(&self) -> usize {
+            2
+        }
+        fn position_hash(&self) -> u64 {
+            self.played.len() as u64
+        }
+    }
+
+    #[test]
+    fn contempt_plays_on_rather_than_draw() {
+        let mut game = DrawOrPlayOn { played: vec![] };
+        assert_eq!(game.get_best_move(1, false), 0);
+        assert_eq!(
+            game.get_best_move_with_config(1, false, SearchConfig::default()),
+            0
+        );
+
+        let config = SearchConfig::default().with_contempt(1.);
+        assert_eq!(game.get_best_move_with_config(1, false, config), 1);
     }
 }
@@ -0,0 +1,127 @@
+use crate::strategy::game_strategy::{GameStrategy, UndoError};
+use core::cell::Cell;
+
+/// Wraps a game and counts how many times
+/// the search plays a move on it, and how many
+/// times it evaluates a position, so that
+/// tests can compare the work done by
+/// different search techniques.
+pub struct CountingGame<G> {
+    pub game: G,
+    pub nodes: u64,
+    pub evaluations: Cell<u64>,
+    /// Whether to use the wrapped game's move ordering
+    /// or to search moves in the order they're generated.
+    pub ordered: bool,
+}
+
+impl<G> CountingGame<G> {
+    pub fn new(game: G) -> Self {
+        Self {
+            game,
+            nodes: 0,
+            evaluations: Cell::new(0),
+            ordered: true,
+        }
+    }
+
+    pub fn without_ordering(game: G) -> Self {
+        Self {
+            ordered: false,
+            ..Self::new(game)
+        }
+    }
+}
+
+impl<G: GameStrategy> GameStrategy for CountingGame<G> {
+    type Player = G::Player;
+    type Move = G::Move;
+    type Board = G::Board;
+
+    const WIN_SCORE: f64 = G::WIN_SCORE;
+    const LOSS_SCORE: f64 = G::LOSS_SCORE;
+
+    fn evaluate(&self) -> f64 {
+        self.evaluations.set(self.evaluations.get() + 1);
+        self.game.evaluate()
+    }
+    fn get_winner(&self) -> Option<Self::Player> {
+        self.game.get_winner()
+    }
+    fn is_game_tied(&self) -> bool {
+        self.game.is_game_tied()
+    }
+    fn is_game_complete(&self) -> bool {
+        self.game.is_game_complete()
+    }
+    fn get_available_moves(&self) -> Vec<Self::Move> {
+        self.game.get_available_moves()
+    }
+    fn moves_for(&self, maximizer: bool) -> Vec<Self::Move> {
+        self.game.moves_for(maximizer)
+    }
+    fn fill_available_moves(&self, moves: &mut Vec<Self::Move>) {
+        self.game.fill_available_moves(moves)
+    }
+    fn play(&mut self, mv: &Self::Move, maximizer: bool) {
+        self.nodes += 1;
+        self.game.play(mv, maximizer)
+    }
+    fn clear(&mut self, mv: &Self::Move) {
+        self.game.clear(mv)
+    }
+    fn undo_move(&mut self) -> Result<(), UndoError> {
+        self.game.undo_move()
+    }
+    fn legal_move_count(&self) -> usize {
+        self.game.legal_move_count()
+    }
+    fn current_player(&self) -> bool {
+        self.game.current_player()
+    }
+    fn ply(&self) -> usize {
+        self.game.ply()
+    }
+    fn get_board(&self) -> &Self::Board {
+        self.game.get_board()
+    }
+    fn board_dimensions(&self) -> Option<(usize, usize)> {
+        self.game.board_dimensions()
+    }
+    fn is_a_valid_move(&self, mv: &Self::Move) -> bool {
+        self.game.is_a_valid_move(mv)
+    }
+    fn get_a_sentinel_move(&self) -> Self::Move {
+        self.game.get_a_sentinel_move()
+    }
+    fn position_hash(&self) -> u64 {
+        self.game.position_hash()
+    }
+    fn reset(&mut self) {
+        self.game.reset()
+    }
+
+    fn get_root_moves(&self) -> Vec<Self::Move> {
+        self.game.get_root_moves()
+    }
+
+    fn move_passes_turn(&self, mv: &Self::Move) -> bool {
+        self.game.move_passes_turn(mv)
+    }
+
+    fn is_quiet(&self) -> bool {
+        self.game.is_quiet()
+    }
+
+    fn get_noisy_moves(&self) -> Vec<Self::Move> {
+        self.game.get_noisy_moves()
+    }
+
+    fn order_moves(&self, moves: Vec<Self::Move>, is_maximizing: bool) -> Vec<Self::Move> {
+        if self.ordered {
+            self.game.order_moves(moves, is_maximizing)
+        } else {
+            moves
+        }
+    }
+}
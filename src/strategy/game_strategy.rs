@@ -1,21 +1,80 @@
-/// Any two-player Minimax game must
-/// have this behavior. In other words,
-/// these functions should yield meaningful outputs
-/// for any two-player games.
+use crate::strategy::zobrist::Rng;
+use alloc::{vec, vec::Vec};
+use core::fmt::Display;
+use core::hash::Hash;
 
+/// The outcome of a completed game, where `Player1` is
+/// the maximizer and `Player2` the minimizer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GameResult {
     Player1Win,
     Player2Win,
     Draw,
 }
 
+/// Why the last move couldn't be taken back with `undo_move`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UndoError {
+    /// The game doesn't keep track of the moves played.
+    Unsupported,
+    /// No move has been played.
+    NothingToUndo,
+}
+
+impl Display for UndoError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            UndoError::Unsupported => write!(f, "This game can't undo moves."),
+            UndoError::NothingToUndo => write!(f, "There is no move to undo."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for UndoError {}
+
+/// The most moves a random playout makes
+/// before it gives up on reaching the end.
+pub const MAX_PLAYOUT_PLIES: usize = 1000;
+
+/// Any two-player Minimax game must
+/// have this behavior. In other words,
+/// these functions should yield meaningful outputs
+/// for any two-player games.
 pub trait GameStrategy {
     type Player;
-    type Move;
+    type Move: Clone + Eq + Hash;
     type Board;
 
+    /// The score of a game the maximizer has won.
+    const WIN_SCORE: f64 = 1000.;
+    /// The score of a game the minimizer has won.
+    const LOSS_SCORE: f64 = -1000.;
+
     /// Ability to statically evaluate the current game state.
     fn evaluate(&self) -> f64;
+    /// Ability to statically evaluate the current game state
+    /// from either player's point of view, which by default
+    /// is `evaluate` for the maximizer and its negation for
+    /// the minimizer. Games whose natural score is relative
+    /// to a side can say so here instead.
+    fn evaluate_for(&self, maximizer: bool) -> f64 {
+        if maximizer {
+            self.evaluate()
+        } else {
+            -self.evaluate()
+        }
+    }
+    /// Ability to statically evaluate many game states at once,
+    /// for evaluators that are much cheaper per position when
+    /// run over a batch of them. By default each one is
+    /// evaluated on its own.
+    fn evaluate_batch(positions: &[&Self]) -> Vec<f64>
+    where
+        Self: Sized,
+    {
+        positions.iter().map(|game| game.evaluate()).collect()
+    }
     /// Identify a winner, if exists.
     fn get_winner(&self) -> Option<Self::Player>;
     /// Identify if the game is tied.
@@ -25,14 +84,184 @@ pub trait GameStrategy {
     /// Ability to produce a collection of playable legal moves
     /// in the current position.
     fn get_available_moves(&self) -> Vec<Self::Move>;
+    /// Ability to produce the moves a given player could make
+    /// if it were their turn, whether or not it is. By default
+    /// these are the available moves, as in games where both
+    /// players choose from the same moves, like placing a mark
+    /// on any empty cell.
+    fn moves_for(&self, _maximizer: bool) -> Vec<Self::Move> {
+        self.get_available_moves()
+    }
+    /// Ability to produce the same moves as `get_available_moves`
+    /// into a list the caller owns, replacing its contents, so
+    /// that the search can reuse one list per ply.
+    fn fill_available_moves(&self, moves: &mut Vec<Self::Move>) {
+        moves.clear();
+        moves.extend(self.get_available_moves());
+    }
+    /// Ability to count the legal moves in the current
+    /// position, for games that can do so without
+    /// producing the moves themselves.
+    fn legal_move_count(&self) -> usize {
+        self.get_available_moves().len()
+    }
+    /// Ability to bound how many more moves the game can last,
+    /// so that the search never looks deeper than that. `None`
+    /// if the game has no such bound, or doesn't know it.
+    fn max_depth_hint(&self) -> Option<i64> {
+        None
+    }
+    /// Ability to tell the rows and columns of the board,
+    /// for games played on a grid, e.g. to show it or to
+    /// read moves given as coordinates. `None` otherwise.
+    fn board_dimensions(&self) -> Option<(usize, usize)> {
+        None
+    }
+    /// Ability to tell if the search can go no further,
+    /// because the game is over or no move is left.
+    fn is_terminal(&self) -> bool {
+        self.is_game_complete() || self.legal_move_count() == 0
+    }
+    /// Ability to produce the moves worth searching at the
+    /// root of a search, which are all the available ones
+    /// unless a game can tell some of them are equivalent.
+    fn get_root_moves(&self) -> Vec<Self::Move> {
+        self.get_available_moves()
+    }
     /// Modify the game state by playing a given move.
     fn play(&mut self, mv: &Self::Move, maximizer: bool);
+    /// Produce the game state reached by playing a given move,
+    /// leaving the current one untouched.
+    fn next_state(&self, mv: &Self::Move, maximizer: bool) -> Self
+    where
+        Self: Clone,
+    {
+        let mut next = self.clone();
+        next.play(mv, maximizer);
+        next
+    }
+    /// Identify if playing a given move, which hasn't been
+    /// played yet, hands the turn over to the other player.
+    /// Games where some moves earn an extra turn can say so here.
+    fn move_passes_turn(&self, _mv: &Self::Move) -> bool {
+        true
+    }
     /// Modify the game state by resetting a given move.
     fn clear(&mut self, mv: &Self::Move);
+    /// Modify the game state by taking back the most recently
+    /// played move, for games that remember the moves played,
+    /// which lets them undo moves `clear` can't easily reverse.
+    fn undo_move(&mut self) -> Result<(), UndoError> {
+        Err(UndoError::Unsupported)
+    }
+    /// Return the game to its initial state.
+    fn reset(&mut self);
+    /// Ability to tell whose turn it is:
+    /// `true` if the maximizer is to move.
+    fn current_player(&self) -> bool;
+    /// Ability to tell how many plies have been played so far,
+    /// e.g. to apply the fifty-move rule or to stop consulting
+    /// an opening book. `0` for games that don't keep count.
+    fn ply(&self) -> usize {
+        0
+    }
     /// Get the current state of the board.
     fn get_board(&self) -> &Self::Board;
     /// Determine if a given move is valid.
     fn is_a_valid_move(&self, mv: &Self::Move) -> bool;
     /// Ability to produce a sentinel (not-playable) move.
     fn get_a_sentinel_move(&self) -> Self::Move;
+    /// Ability to summarize the current position in a hash,
+    /// so that identical positions reached by different
+    /// move orders can be recognized.
+    fn position_hash(&self) -> u64;
+    /// Ability to tell how promising a move looks at a glance,
+    /// e.g. by what it captures or where it is played, with
+    /// higher scores first in line to be searched. By default
+    /// every move looks as promising as any other.
+    fn score_move(&self, _mv: &Self::Move) -> i32 {
+        0
+    }
+    /// Ability to rearrange the moves about to be searched
+    /// so that the most promising ones come first, which
+    /// lets alpha-beta pruning cut off more of the tree.
+    /// `is_maximizing` tells whether the maximizer is the
+    /// one to play these moves. By default they're sorted
+    /// by `score_move`, keeping the order of those tied.
+    fn order_moves(&self, mut moves: Vec<Self::Move>, _is_maximizing: bool) -> Vec<Self::Move> {
+        moves.sort_by_key(|mv| core::cmp::Reverse(self.score_move(mv)));
+        moves
+    }
+    /// Identify if the position is calm enough to be
+    /// scored by `evaluate` alone. A position that isn't,
+    /// e.g. one in the middle of a capture exchange, is
+    /// searched further through `get_noisy_moves` once
+    /// the depth limit is reached.
+    fn is_quiet(&self) -> bool {
+        true
+    }
+    /// Ability to produce the moves, e.g. captures, that
+    /// should still be searched past the depth limit
+    /// while the position isn't quiet.
+    fn get_noisy_moves(&self) -> Vec<Self::Move> {
+        vec![]
+    }
+    /// Ability to estimate the current game state by playing
+    /// `n` games out to the end with random moves drawn from
+    /// `rng`, each on a clone of the game, and averaging how
+    /// they ended. A playout that doesn't end within
+    /// `MAX_PLAYOUT_PLIES` is scored by `evaluate`.
+    fn rollout_evaluate(&self, n: usize, rng: &mut impl Rng) -> f64
+    where
+        Self: Clone,
+    {
+        if n == 0 {
+            return self.evaluate();
+        }
+        let mut total = 0.;
+        for _ in 0..n {
+            let mut game = self.clone();
+            for _ in 0..MAX_PLAYOUT_PLIES {
+                if game.is_terminal() {
+                    break;
+                }
+                let mv = random_move(&game, rng);
+                let maximizer = game.current_player();
+                game.play(&mv, maximizer);
+            }
+            total += playout_score(&game);
+        }
+        total / n as f64
+    }
+    /// Ability to summarize how a completed game ended,
+    /// or `None` if it is still in progress. By default
+    /// the winner is told apart by the sign of `evaluate`.
+    fn result(&self) -> Option<GameResult> {
+        if !self.is_game_complete() {
+            None
+        } else if self.is_game_tied() || self.get_winner().is_none() {
+            Some(GameResult::Draw)
+        } else if self.evaluate() > 0. {
+            Some(GameResult::Player1Win)
+        } else {
+            Some(GameResult::Player2Win)
+        }
+    }
+}
+
+/// A move picked uniformly at random from those available.
+pub(crate) fn random_move<T: GameStrategy>(game: &T, rng: &mut impl Rng) -> T::Move {
+    let moves = game.get_available_moves();
+    moves[(rng.next_u64() % moves.len() as u64) as usize].clone()
+}
+
+/// The score of the position a playout stopped at:
+/// how the game ended, or `evaluate` if it hasn't.
+pub(crate) fn playout_score<T: GameStrategy>(game: &T) -> f64 {
+    match game.result() {
+        Some(GameResult::Player1Win) => T::WIN_SCORE,
+        Some(GameResult::Player2Win) => T::LOSS_SCORE,
+        Some(GameResult::Draw) => 0.,
+        None => game.evaluate(),
+    }
 }
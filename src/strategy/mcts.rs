@@ -0,0 +1,208 @@
+use crate::strategy::alpha_beta_minimax::clock_seed;
+use crate::strategy::game_strategy::{GameResult, GameStrategy, MAX_PLAYOUT_PLIES};
+use crate::strategy::zobrist::splitmix64;
+
+/// How strongly UCT favours rarely visited moves
+/// over ones that have done well so far.
+pub const EXPLORATION: f64 = std::f64::consts::SQRT_2;
+
+/// Any game that can be copied can be searched with
+/// Monte-Carlo Tree Search, which needs no evaluation
+/// function and so suits games too deep for minimax.
+pub trait MctsStrategy: GameStrategy {
+    /// The ability to get the best move for the player
+    /// to move by growing a search tree over `iterations`
+    /// random playouts, choosing which line to play out
+    /// next by UCT.
+    fn get_best_move_mcts(&mut self, iterations: usize) -> <Self as GameStrategy>::Move;
+
+    /// The same as `get_best_move_mcts`, but drawing the
+    /// random moves from the given seed, so that the search
+    /// can be reproduced.
+    fn get_best_move_mcts_seeded(
+        &mut self,
+        iterations: usize,
+        seed: u64,
+    ) -> <Self as GameStrategy>::Move;
+}
+
+/// A position in the search tree.
+struct Node<G: GameStrategy> {
+    state: G,
+    /// The move that led here, and whether the maximizer played it.
+    played: Option<(G::Move, bool)>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+    /// The moves from here not yet added to the tree.
+    untried: Vec<G::Move>,
+    visits: u64,
+    /// The total reward of the playouts through this node,
+    /// for the player who made the move that led here.
+    reward: f64,
+}
+
+impl<G: GameStrategy + Clone> Node<G> {
+    fn new(state: G, played: Option<(G::Move, bool)>, parent: Option<usize>) -> Self {
+        let untried = if state.is_terminal() {
+            vec![]
+        } else {
+            state.get_available_moves()
+        };
+        Self {
+            state,
+            played,
+            parent,
+            children: vec![],
+            untried,
+            visits: 0,
+            reward: 0.,
+        }
+    }
+
+    /// The UCT score of this node, from a parent visited `parent_visits` times.
+    fn uct(&self, parent_visits: u64) -> f64 {
+        let visits = self.visits as f64;
+        self.reward / visits + EXPLORATION * ((parent_visits as f64).ln() / visits).sqrt()
+    }
+}
+
+/// Play random moves until the game ends, and
+/// score how it ended for the maximizer: 1 for a
+/// win, 0 for a loss, and a half for anything else.
+fn playout<G: GameStrategy>(mut game: G, rng: &mut u64) -> f64 {
+    for _ in 0..MAX_PLAYOUT_PLIES {
+        if game.is_terminal() {
+            break;
+        }
+        let moves = game.get_available_moves();
+        let mv = &moves[(splitmix64(rng) % moves.len() as u64) as usize];
+        let maximizer = game.current_player();
+        game.play(mv, maximizer);
+    }
+    match game.result() {
+        Some(GameResult::Player1Win) => 1.,
+        Some(GameResult::Player2Win) => 0.,
+        _ => 0.5,
+    }
+}
+
+/// Endow upon anything that can be copied
+/// the ability to search with MCTS.
+impl<T: GameStrategy + Clone> MctsStrategy for T {
+    fn get_best_move_mcts(&mut self, iterations: usize) -> <Self as GameStrategy>::Move {
+        self.get_best_move_mcts_seeded(iterations, clock_seed())
+    }
+
+    fn get_best_move_mcts_seeded(
+        &mut self,
+        iterations: usize,
+        seed: u64,
+    ) -> <Self as GameStrategy>::Move {
+        let mut rng = seed;
+        let mut tree = vec![Node::new(self.clone(), None, None)];
+
+        for _ in 0..iterations {
+            // Selection: follow the best UCT score down
+            // to a node that isn't fully expanded yet.
+            let mut idx = 0;
+            while tree[idx].untried.is_empty() && !tree[idx].children.is_empty() {
+                let parent_visits = tree[idx].visits;
+                let mut best = (tree[idx].children[0], f64::NEG_INFINITY);
+                for &child in &tree[idx].children {
+                    let score = tree[child].uct(parent_visits);
+                    if score > best.1 {
+                        best = (child, score);
+                    }
+                }
+                idx = best.0;
+            }
+
+            // Expansion: add one of its untried moves.
+            if !tree[idx].untried.is_empty() {
+                let pick = (splitmix64(&mut rng) % tree[idx].untried.len() as u64) as usize;
+                let mv = tree[idx].untried.swap_remove(pick);
+                let maximizer = tree[idx].state.current_player();
+                let state = tree[idx].state.next_state(&mv, maximizer);
+                tree.push(Node::new(state, Some((mv, maximizer)), Some(idx)));
+                let child = tree.len() - 1;
+                tree[idx].children.push(child);
+                idx = child;
+            }
+
+            // Simulation, then backpropagation of the
+            // result to every node on the way back up.
+            let outcome = playout(tree[idx].state.clone(), &mut rng);
+            let mut node = Some(idx);
+            while let Some(idx) = node {
+                tree[idx].visits += 1;
+                tree[idx].reward += match tree[idx].played {
+                    Some((_, true)) => outcome,
+                    Some((_, false)) => 1. - outcome,
+                    None => 0.,
+                };
+                node = tree[idx].parent;
+            }
+        }
+
+        // The most visited move is the one the search trusts most.
+        tree[0]
+            .children
+            .iter()
+            .max_by_key(|&&child| tree[child].visits)
+            .and_then(|&child| tree[child].played.clone())
+            .map_or_else(|| self.get_a_sentinel_move(), |(mv, _)| mv)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::TicTacToe;
+
+    /// Play a game of TicTacToe between MCTS and an opponent
+    /// moving at random, returning how it ended.
+    fn against_random(mcts_is_maximizer: bool, seed: u64) -> Option<GameResult> {
+        let mut game = TicTacToe::new(3);
+        let mut rng = seed;
+        while !game.is_game_complete() {
+            let maximizer = game.current_player();
+            let mv = if maximizer == mcts_is_maximizer {
+                game.get_best_move_mcts_seeded(2000, splitmix64(&mut rng))
+            } else {
+                let moves = game.get_available_moves();
+                moves[(splitmix64(&mut rng) % moves.len() as u64) as usize]
+            };
+            game.play(&mv, maximizer);
+        }
+        game.result()
+    }
+
+    #[test]
+    fn mcts_takes_a_win_in_one() {
+        let mut game = TicTacToe::new(3);
+        for (mv, maximizer) in [(0, true), (3, false), (1, true), (4, false)] {
+            game.play(&mv, maximizer);
+        }
+        assert_eq!(game.get_best_move_mcts_seeded(500, 1), 2);
+    }
+
+    #[test]
+    fn mcts_never_loses_to_a_random_player() {
+        for seed in 0..5 {
+            assert_ne!(against_random(true, seed), Some(GameResult::Player2Win));
+            assert_ne!(against_random(false, seed), Some(GameResult::Player1Win));
+        }
+    }
+
+    #[test]
+    fn a_finished_game_has_no_move() {
+        let mut game = TicTacToe::new(3);
+        for (mv, maximizer) in [(0, true), (3, false), (1, true), (4, false), (2, true)] {
+            game.play(&mv, maximizer);
+        }
+        assert_eq!(
+            game.get_best_move_mcts_seeded(100, 1),
+            game.get_a_sentinel_move()
+        );
+    }
+}
@@ -1,2 +1,155 @@
 pub mod alpha_beta_minimax;
+#[cfg(test)]
+pub(crate) mod counting;
 pub mod game_strategy;
+#[cfg(feature = "std")]
+pub mod mcts;
+pub mod scored;
+pub mod self_play;
+pub mod transposition;
+pub mod zobrist;
+
+use alpha_beta_minimax::{AlphaBetaMiniMaxStrategy, SearchConfig, SearchContext};
+use game_strategy::GameStrategy;
+
+/// The fewest moves at the root worth handing out
+/// to separate threads in a parallel search.
+pub const DEFAULT_PARALLEL_THRESHOLD: usize = 4;
+
+/// The games a `SearchEngine` can search: any game, or with
+/// the `parallel` feature, those that can be handed to other
+/// threads, along with their moves.
+#[cfg(feature = "parallel")]
+pub trait EngineGame: GameStrategy<Move: Send> + Clone + Send {}
+
+#[cfg(feature = "parallel")]
+impl<G: GameStrategy<Move: Send> + Clone + Send> EngineGame for G {}
+
+/// The games a `SearchEngine` can search: any game, or with
+/// the `parallel` feature, those that can be handed to other
+/// threads, along with their moves.
+#[cfg(not(feature = "parallel"))]
+pub trait EngineGame: GameStrategy {}
+
+#[cfg(not(feature = "parallel"))]
+impl<G: GameStrategy> EngineGame for G {}
+
+/// A search set up once and used to pick moves
+/// for whichever player is to move, without having
+/// to choose among the strategy's search variants.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SearchEngine {
+    /// How many plies to search.
+    pub depth: i64,
+    /// Whether to search the root moves in parallel,
+    /// if the `parallel` feature is enabled.
+    pub parallel: bool,
+    /// The fewest moves at the root for which
+    /// a parallel search is used.
+    pub parallel_threshold: usize,
+    /// Whether to order moves by the history heuristic.
+    pub history: bool,
+    /// Whether to use principal variation search.
+    pub pvs: bool,
+    /// How to judge positions.
+    pub config: SearchConfig,
+}
+
+impl SearchEngine {
+    /// A sequential search to the given depth.
+    pub fn new(depth: i64) -> Self {
+        Self {
+            depth,
+            parallel: false,
+            parallel_threshold: DEFAULT_PARALLEL_THRESHOLD,
+            history: false,
+            pvs: false,
+            config: SearchConfig::default(),
+        }
+    }
+
+    pub fn with_parallel(self, parallel: bool) -> Self {
+        Self { parallel, ..self }
+    }
+
+    pub fn with_parallel_threshold(self, parallel_threshold: usize) -> Self {
+        Self {
+            parallel_threshold,
+            ..self
+        }
+    }
+
+    pub fn with_history(self, history: bool) -> Self {
+        Self { history, ..self }
+    }
+
+    pub fn with_pvs(self, pvs: bool) -> Self {
+        Self { pvs, ..self }
+    }
+
+    pub fn with_config(self, config: SearchConfig) -> Self {
+        Self { config, ..self }
+    }
+
+    /// Whether a search of the given game would run in parallel.
+    #[cfg(feature = "parallel")]
+    fn runs_in_parallel<G: GameStrategy>(&self, game: &G) -> bool {
+        self.parallel
+            && self.config == SearchConfig::default()
+            && game.get_root_moves().len() >= self.parallel_threshold
+    }
+
+    /// The best move for the player to move,
+    /// searched in parallel if the engine is set up to.
+    ///
+    /// The parallel search scores every root move with a
+    /// fresh search of its own, so it leaves the history
+    /// and PVS heuristics off, and isn't used at all
+    /// with a non-default configuration.
+    pub fn best_move<G: EngineGame>(&self, game: &mut G) -> G::Move {
+        // The player to move is the opposite of the one named.
+        let is_maximizing = !game.current_player();
+        #[cfg(feature = "parallel")]
+        if self.runs_in_parallel(game) {
+            return game.get_best_move_parallel(self.depth, is_maximizing);
+        }
+
+        let mut context = SearchContext::new().with_config(self.config);
+        if self.history {
+            context = context.with_history();
+        }
+        if self.pvs {
+            context = context.with_pvs();
+        }
+        game.get_best_move_with_context(self.depth, is_maximizing, &mut context)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::TicTacToe;
+
+    #[test]
+    fn engine_matches_the_plain_search() {
+        let mut ttt = TicTacToe::new(3);
+        ttt.play(&4, true);
+        ttt.play(&0, false);
+
+        let engine = SearchEngine::new(9).with_history(true).with_pvs(true);
+        assert_eq!(engine.best_move(&mut ttt), ttt.get_best_move(9, true));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn parallel_engine_agrees_with_sequential() {
+        let mut ttt = TicTacToe::new(3);
+        ttt.play(&4, true);
+        ttt.play(&0, false);
+
+        let sequential = SearchEngine::new(9);
+        let parallel = sequential.with_parallel(true);
+        assert!(parallel.runs_in_parallel(&ttt));
+        assert_eq!(parallel.best_move(&mut ttt), sequential.best_move(&mut ttt));
+    }
+}
@@ -0,0 +1,247 @@
+use crate::strategy::alpha_beta_minimax::{
+    search_root_by, SearchContext, Valuation, SCORE_EPSILON,
+};
+use crate::strategy::game_strategy::GameStrategy;
+use core::cmp::Ordering;
+use core::ops::Neg;
+
+/// Anything the search can use as a score:
+/// totally ordered, cheap to copy, and negatable
+/// so that a score can be seen from either side.
+pub trait Score: Copy + Ord + Neg<Output = Self> {
+    /// A score better than any a game produces.
+    /// Its negation is worse than any a game produces.
+    const MAX: Self;
+
+    /// Pull a decisive score toward zero by one unit per ply.
+    fn toward_zero(self, plies: i64) -> Self;
+
+    /// The least score above this one, which bounds
+    /// the null window of a principal variation search.
+    fn above(self) -> Self;
+
+    /// This score as an `f64`, for the parts of the search,
+    /// e.g. the transposition table, that store scores as such.
+    fn to_f64(self) -> f64;
+
+    /// The score closest to an `f64`, e.g. a contempt
+    /// or a playout's average, saturating at the ends.
+    fn from_f64(score: f64) -> Self;
+}
+
+impl Score for i32 {
+    const MAX: Self = i32::MAX;
+
+    fn toward_zero(self, plies: i64) -> Self {
+        if self > 0 {
+            self - plies as i32
+        } else {
+            self + plies as i32
+        }
+    }
+
+    fn above(self) -> Self {
+        self.saturating_add(1)
+    }
+
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(score: f64) -> Self {
+        score as i32
+    }
+}
+
+impl Score for i64 {
+    const MAX: Self = i64::MAX;
+
+    fn toward_zero(self, plies: i64) -> Self {
+        if self > 0 {
+            self - plies
+        } else {
+            self + plies
+        }
+    }
+
+    fn above(self) -> Self {
+        self.saturating_add(1)
+    }
+
+    /// Exact within `±2^53`, well beyond any game's scores.
+    fn to_f64(self) -> f64 {
+        self as f64
+    }
+
+    fn from_f64(score: f64) -> Self {
+        score as i64
+    }
+}
+
+/// An `f64` that is totally ordered, so that it can be
+/// used as a `Score` by games whose evaluation isn't exact.
+#[derive(Debug, Clone, Copy)]
+pub struct OrderedF64(pub f64);
+
+impl PartialEq for OrderedF64 {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for OrderedF64 {}
+
+impl PartialOrd for OrderedF64 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Ordered as `f64`s are, so that `-0.` and `0.` are equal,
+/// with only NaNs placed at either end by their sign.
+impl Ord for OrderedF64 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.0
+            .partial_cmp(&other.0)
+            .unwrap_or_else(|| self.0.total_cmp(&other.0))
+    }
+}
+
+impl Neg for OrderedF64 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+impl Score for OrderedF64 {
+    const MAX: Self = Self(f64::INFINITY);
+
+    fn toward_zero(self, plies: i64) -> Self {
+        if self.0 > 0. {
+            Self(self.0 - plies as f64)
+        } else {
+            Self(self.0 + plies as f64)
+        }
+    }
+
+    fn above(self) -> Self {
+        Self(self.0 + SCORE_EPSILON)
+    }
+
+    fn to_f64(self) -> f64 {
+        self.0
+    }
+
+    fn from_f64(score: f64) -> Self {
+        Self(score)
+    }
+}
+
+/// A game that can also evaluate its states with a score
+/// of its own choosing, e.g. an integer for exact games,
+/// rather than only with the `f64` of `GameStrategy::evaluate`.
+pub trait ScoredGameStrategy: GameStrategy {
+    type Score: Score;
+
+    /// Ability to statically evaluate the current game state.
+    fn score(&self) -> Self::Score;
+}
+
+/// The behaviour required of a minimax game engine
+/// that compares scores of the game's own type.
+pub trait ScoredMiniMaxStrategy: ScoredGameStrategy {
+    /// The ability to get the best move
+    /// in the current state and for the
+    /// current player, along with its score.
+    fn get_best_move_scored(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> (
+        <Self as GameStrategy>::Move,
+        <Self as ScoredGameStrategy>::Score,
+    );
+}
+
+/// Values positions by `ScoredGameStrategy::score`.
+pub(crate) struct Scored;
+
+impl<T: ScoredGameStrategy> Valuation<T> for Scored {
+    type Score = T::Score;
+
+    fn evaluate(game: &T) -> T::Score {
+        game.score()
+    }
+
+    fn evaluate_for(game: &T, maximizer: bool) -> T::Score {
+        if maximizer {
+            game.score()
+        } else {
+            -game.score()
+        }
+    }
+
+    /// Pull the score of a won or lost game toward zero
+    /// by the number of plies it took to reach, so that
+    /// quicker wins and slower losses are preferred.
+    fn terminal_adjusted(game: &T, score: T::Score, ply: i64) -> T::Score {
+        if game.is_game_complete() && !game.is_game_tied() {
+            score.toward_zero(ply)
+        } else {
+            score
+        }
+    }
+}
+
+/// Endow upon anything that has a score of its own
+/// the ability to be searched with that score.
+impl<T: ScoredGameStrategy> ScoredMiniMaxStrategy for T {
+    fn get_best_move_scored(
+        &mut self,
+        max_depth: i64,
+        is_maximizing: bool,
+    ) -> (
+        <Self as GameStrategy>::Move,
+        <Self as ScoredGameStrategy>::Score,
+    ) {
+        search_root_by::<_, Scored>(
+            self,
+            max_depth,
+            is_maximizing,
+            -T::Score::MAX,
+            T::Score::MAX,
+            &mut SearchContext::default(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ordered_f64_is_totally_ordered() {
+        let mut scores = vec![
+            OrderedF64(1.5),
+            -OrderedF64::MAX,
+            OrderedF64(-2.),
+            OrderedF64::MAX,
+        ];
+        scores.sort();
+        assert_eq!(
+            scores,
+            vec![
+                OrderedF64(f64::NEG_INFINITY),
+                OrderedF64(-2.),
+                OrderedF64(1.5),
+                OrderedF64(f64::INFINITY)
+            ]
+        );
+        assert_eq!(OrderedF64(-0.), OrderedF64(0.));
+        assert!(OrderedF64(f64::NAN) > OrderedF64::MAX);
+        assert_eq!(OrderedF64(1000.).toward_zero(3), OrderedF64(997.));
+        assert_eq!((-1000i32).toward_zero(3), -997);
+    }
+}
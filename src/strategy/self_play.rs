@@ -0,0 +1,47 @@
+use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+use crate::strategy::game_strategy::{GameResult, GameStrategy};
+
+/// Play a game out between two engines searching to
+/// different depths, the one searching `depth_a` plies
+/// playing as the maximizer.
+///
+/// Returns how the game ended, or `None` if a player
+/// was left without a move before it was complete.
+pub fn self_play<G: GameStrategy + Clone>(
+    mut game: G,
+    depth_a: i64,
+    depth_b: i64,
+) -> Option<GameResult> {
+    while !game.is_game_complete() {
+        let maximizer = game.current_player();
+        let depth = if maximizer { depth_a } else { depth_b };
+        let mv = game.get_best_move_for_current_player(depth);
+        if !game.is_a_valid_move(&mv) {
+            break;
+        }
+        game.play(&mv, maximizer);
+    }
+    game.result()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::{Nim, TicTacToe};
+
+    #[test]
+    fn perfect_tic_tac_toe_is_a_draw() {
+        for _ in 0..3 {
+            assert_eq!(self_play(TicTacToe::new(3), 9, 9), Some(GameResult::Draw));
+        }
+    }
+
+    #[test]
+    fn the_first_player_converts_a_won_position() {
+        // Evening out the heaps wins for the first player.
+        assert_eq!(
+            self_play(Nim::new(vec![1, 2]), 4, 4),
+            Some(GameResult::Player1Win)
+        );
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(not(feature = "std"))]
+use alloc::collections::BTreeMap as HashMap;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// How a stored score relates to the
+/// true minimax value of a position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NodeType {
+    /// The score is the exact minimax value.
+    Exact,
+    /// The search failed high, so the true
+    /// value is at least the score.
+    LowerBound,
+    /// The search failed low, so the true
+    /// value is at most the score.
+    UpperBound,
+}
+
+/// What is remembered about a searched position.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TranspositionEntry {
+    /// The remaining depth the position was searched to.
+    pub depth: i64,
+    /// The score found by that search.
+    pub score: f64,
+    /// Whether the score is exact or a bound.
+    pub node_type: NodeType,
+}
+
+/// A cache of search results keyed by position hash,
+/// so that a position reached by different move orders
+/// only has to be expanded once.
+#[derive(Debug, Clone, Default)]
+pub struct TranspositionTable {
+    entries: HashMap<(u64, bool), TranspositionEntry>,
+}
+
+impl TranspositionTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Look up a position searched with the given
+    /// player to move. Only entries searched at least
+    /// as deep as `depth` are returned.
+    pub fn probe(&self, hash: u64, is_maximizing: bool, depth: i64) -> Option<TranspositionEntry> {
+        self.entries
+            .get(&(hash, is_maximizing))
+            .filter(|entry| entry.depth >= depth)
+            .copied()
+    }
+
+    /// Remember the result of searching a position,
+    /// keeping whichever of the old and new entries
+    /// was searched deeper.
+    pub fn store(&mut self, hash: u64, is_maximizing: bool, entry: TranspositionEntry) {
+        let slot = self.entries.entry((hash, is_maximizing)).or_insert(entry);
+        if entry.depth >= slot.depth {
+            *slot = entry;
+        }
+    }
+
+    /// The number of positions stored.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Whether no positions are stored.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Forget every stored position.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::games::TicTacToe;
+    use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+    use crate::strategy::counting::CountingGame;
+    use crate::strategy::game_strategy::GameStrategy;
+
+    #[test]
+    fn probe_respects_depth_and_side() {
+        let mut table = TranspositionTable::new();
+        let entry = TranspositionEntry {
+            depth: 3,
+            score: 1.,
+            node_type: NodeType::Exact,
+        };
+        table.store(42, true, entry);
+
+        assert_eq!(table.probe(42, true, 3), Some(entry));
+        assert_eq!(table.probe(42, true, 2), Some(entry));
+        assert_eq!(table.probe(42, true, 4), None);
+        assert_eq!(table.probe(42, false, 3), None);
+    }
+
+    #[test]
+    fn table_reduces_nodes_on_4_by_4() {
+        let mut ttt = TicTacToe::new(4);
+        for (idx, mv) in [0, 5, 10, 15, 3, 12].iter().enumerate() {
+            ttt.play(mv, idx % 2 == 0);
+        }
+
+        let mut plain = CountingGame::new(ttt.clone());
+        let expected = plain.get_best_move(6, true);
+
+        let mut cached = CountingGame::new(ttt);
+        let mut table = TranspositionTable::new();
+        let found = cached.get_best_move_with_table(6, true, &mut table);
+
+        assert_eq!(found, expected);
+        assert!(!table.is_empty());
+        assert!(cached.nodes < plain.nodes);
+    }
+}
@@ -0,0 +1,77 @@
+use alloc::vec::Vec;
+
+/// The seed every table is generated from, so that
+/// tables for boards of the same size hold the same keys.
+const SEED: u64 = 0x2545_f491_4f6c_dd1d;
+
+/// Random keys for Zobrist hashing: one per cell and symbol.
+/// A position hashes to the XOR of the keys of its occupied
+/// cells, so playing or clearing a move updates the hash by
+/// XOR-ing in a single key.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZobristTable {
+    keys: Vec<u64>,
+    symbols: usize,
+}
+
+impl ZobristTable {
+    /// Generate keys for a board of `cells` cells,
+    /// each of which can hold any of `symbols` symbols.
+    pub fn new(cells: usize, symbols: usize) -> Self {
+        let mut state = SEED;
+        Self {
+            keys: (0..cells * symbols)
+                .map(|_| splitmix64(&mut state))
+                .collect(),
+            symbols,
+        }
+    }
+
+    /// The key of a given symbol on a given cell.
+    pub fn key(&self, cell: usize, symbol: usize) -> u64 {
+        self.keys[cell * self.symbols + symbol]
+    }
+}
+
+/// A source of random numbers, e.g. for playouts.
+/// Any generator, such as one from `rand`,
+/// can be used by wrapping it in this trait.
+pub trait Rng {
+    fn next_u64(&mut self) -> u64;
+}
+
+/// A `u64` is the state of a SplitMix64 generator.
+impl Rng for u64 {
+    fn next_u64(&mut self) -> u64 {
+        splitmix64(self)
+    }
+}
+
+/// The SplitMix64 generator: cheap, and good enough
+/// to spread keys evenly over all 64 bits.
+pub(crate) fn splitmix64(state: &mut u64) -> u64 {
+    *state = state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    let mut z = *state;
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keys_are_deterministic_and_distinct() {
+        let table = ZobristTable::new(9, 2);
+        assert_eq!(table, ZobristTable::new(9, 2));
+
+        let mut keys: Vec<u64> = (0..9)
+            .flat_map(|cell| (0..2).map(move |symbol| (cell, symbol)))
+            .map(|(cell, symbol)| table.key(cell, symbol))
+            .collect();
+        keys.sort_unstable();
+        keys.dedup();
+        assert_eq!(keys.len(), 18);
+    }
+}
@@ -0,0 +1,85 @@
+use crate::games::TicTacToe;
+use crate::strategy::alpha_beta_minimax::AlphaBetaMiniMaxStrategy;
+use crate::strategy::game_strategy::GameStrategy;
+#[cfg(feature = "wasm")]
+use wasm_bindgen::prelude::*;
+
+/// A TicTacToe game for the browser, whose moves are
+/// flat indices and whose board is a string, since
+/// neither `char` nor `Vec<char>` binds cleanly.
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+pub struct WasmTicTacToe {
+    game: TicTacToe,
+}
+
+#[cfg_attr(feature = "wasm", wasm_bindgen)]
+impl WasmTicTacToe {
+    #[cfg_attr(feature = "wasm", wasm_bindgen(constructor))]
+    pub fn new(size: usize) -> WasmTicTacToe {
+        WasmTicTacToe {
+            game: TicTacToe::new(size),
+        }
+    }
+
+    /// Play a move for whoever's turn it is, returning
+    /// whether it was played. Moves off the board, on a
+    /// taken cell, or after the game is over are refused.
+    pub fn play(&mut self, index: usize) -> bool {
+        if self.game.is_game_complete() {
+            return false;
+        }
+        let maximizer = self.game.current_player();
+        self.game.try_play(index, maximizer).is_ok()
+    }
+
+    /// The engine's move for whoever's turn it is,
+    /// or -1 if the game is over.
+    pub fn best_move(&mut self, depth: u32) -> i32 {
+        if self.game.is_game_complete() {
+            return -1;
+        }
+        let mv = self.game.get_best_move_for_current_player(depth as i64);
+        if self.game.is_a_valid_move(&mv) {
+            mv as i32
+        } else {
+            -1
+        }
+    }
+
+    /// Whether someone has won or the board is full.
+    pub fn is_game_complete(&self) -> bool {
+        self.game.is_game_complete()
+    }
+
+    /// The board, one row per line.
+    pub fn board(&self) -> String {
+        self.game.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wrapper_plays_a_game_through_indices_and_strings() {
+        let mut game = WasmTicTacToe::new(3);
+        assert!(game.play(0));
+        assert!(!game.play(0));
+        assert!(!game.play(9));
+        assert!(game.play(4));
+        assert!(game.play(1));
+        assert_eq!(game.board(), "oo-\n-x-\n---\n");
+
+        // The minimizer must block the top row.
+        assert_eq!(game.best_move(9), 2);
+        assert!(game.play(2));
+
+        while !game.is_game_complete() {
+            let mv = game.best_move(9);
+            assert!(game.play(mv as usize));
+        }
+        assert_eq!(game.best_move(9), -1);
+        assert!(!game.play(8));
+    }
+}